@@ -6,3 +6,4 @@ pub mod price;
 pub mod accumulator_update;
 pub mod batch_attestation_update;
 pub mod update_type;
+pub mod governance_instruction;
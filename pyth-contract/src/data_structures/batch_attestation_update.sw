@@ -1,6 +1,7 @@
 library;
 
 use ::errors::PythError;
+use ::events::PriceFeedUpdateEvent;
 use ::data_structures::{data_source::*, price::*, wormhole_light::{StorageGuardianSet, WormholeVM}};
 use pyth_interface::data_structures::{data_source::DataSource, price::{PriceFeed, PriceFeedId}};
 use std::{bytes::Bytes, hash::Hash};
@@ -42,6 +43,7 @@ impl BatchAttestationUpdate {
             if price_feed.price.publish_time > latest_publish_time {
                 latest_price_feed.insert(price_feed.id, price_feed);
                 updated_ids.push(price_feed.id);
+                log(PriceFeedUpdateEvent::new(price_feed));
             }
             i += 1;
         }
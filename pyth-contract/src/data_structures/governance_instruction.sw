@@ -0,0 +1,156 @@
+library;
+
+use ::data_structures::data_source::*;
+use ::errors::PythError;
+use pyth_interface::data_structures::data_source::DataSource;
+use std::{array_conversions::{b256::*, u16::*, u32::*, u64::*}, bytes::Bytes};
+
+/// "PTGM", the magic of Pyth governance messages.
+const GOVERNANCE_MAGIC: u32 = 0x5054474d;
+/// The module of governance instructions targeting Pyth receiver contracts.
+const TARGET_CHAIN_MODULE: u8 = 1;
+/// Fuel has no Wormhole chain id, so only instructions targeting every chain are accepted.
+const ALL_CHAINS: u16 = 0;
+
+const SET_DATA_SOURCES: u8 = 2;
+const SET_FEE: u8 = 3;
+const SET_VALID_PERIOD: u8 = 4;
+/// A Fuel-specific extension rather than one of Pyth's target chain actions: its payload, the
+/// amount (u64), recipient type (u8) and recipient (b256) of 41 bytes, is not produced by Pyth's
+/// governance tooling and must be encoded for this contract.
+const WITHDRAW_FEES: u8 = 9;
+
+pub enum GovernanceAction {
+    SetDataSources: Vec<DataSource>,
+    /// The new single update fee, in base asset units.
+    SetFee: u64,
+    /// The new valid time period, in seconds.
+    SetValidPeriod: u64,
+    WithdrawFees: FeeWithdrawal,
+}
+
+/// Base asset fees to transfer out of the contract.
+pub struct FeeWithdrawal {
+    amount: u64,
+    recipient: Identity,
+}
+
+/// A governance instruction for every chain, the only target chain id the contract accepts.
+pub struct GovernanceInstruction {
+    action: GovernanceAction,
+}
+
+impl GovernanceInstruction {
+    /// Parses the payload of a governance VAA: the "PTGM" magic, module (u8), action (u8) and
+    /// target chain id (u16), followed by the payload of the action.
+    pub fn parse(encoded_instruction: Bytes) -> Self {
+        require(
+            encoded_instruction
+                .len >= 8,
+            PythError::InvalidGovernanceMessage,
+        );
+        let magic = u32::from_be_bytes([
+            encoded_instruction.get(0).unwrap(),
+            encoded_instruction.get(1).unwrap(),
+            encoded_instruction.get(2).unwrap(),
+            encoded_instruction.get(3).unwrap(),
+        ]);
+        require(magic == GOVERNANCE_MAGIC, PythError::InvalidGovernanceMessage);
+        let module = encoded_instruction.get(4).unwrap();
+        require(
+            module == TARGET_CHAIN_MODULE,
+            PythError::InvalidGovernanceModule,
+        );
+        let action = encoded_instruction.get(5).unwrap();
+        let target_chain_id = read_u16(encoded_instruction, 6);
+        require(
+            target_chain_id == ALL_CHAINS,
+            PythError::InvalidGovernanceTarget,
+        );
+        let (_, payload) = encoded_instruction.split_at(8);
+
+        let action = if action == SET_DATA_SOURCES {
+            GovernanceAction::SetDataSources(parse_data_sources(payload))
+        } else if action == SET_FEE {
+            require(payload.len == 16, PythError::InvalidGovernanceMessage);
+            let value = read_u64(payload, 0);
+            let expo = read_u64(payload, 8);
+            let mut fee = value;
+            let mut i = 0;
+            while i < expo {
+                fee = fee * 10;
+                i += 1;
+            }
+            GovernanceAction::SetFee(fee)
+        } else if action == SET_VALID_PERIOD {
+            require(payload.len == 8, PythError::InvalidGovernanceMessage);
+            GovernanceAction::SetValidPeriod(read_u64(payload, 0))
+        } else {
+            require(action == WITHDRAW_FEES, PythError::InvalidGovernanceAction);
+            GovernanceAction::WithdrawFees(parse_fee_withdrawal(payload))
+        };
+
+        Self { action }
+    }
+}
+
+/// Parses the number of data sources (u8), followed by the chain id (u16) and emitter address
+/// (b256) of each data source.
+fn parse_data_sources(payload: Bytes) -> Vec<DataSource> {
+    require(payload.len > 0, PythError::InvalidGovernanceMessage);
+    let number_of_data_sources = payload.get(0).unwrap().as_u64();
+    require(
+        payload
+            .len == 1 + number_of_data_sources * 34,
+        PythError::InvalidGovernanceMessage,
+    );
+    let mut data_sources = Vec::with_capacity(number_of_data_sources);
+    let mut index = 1;
+    while index < payload.len {
+        let chain_id = read_u16(payload, index);
+        let (_, slice) = payload.split_at(index + 2);
+        let (emitter_address, _) = slice.split_at(32);
+        let emitter_address: b256 = emitter_address.into();
+        data_sources.push(DataSource::new(chain_id, emitter_address));
+        index += 34;
+    }
+    data_sources
+}
+
+/// Parses the amount (u64), followed by the type of the recipient (u8, 0 for an address and 1
+/// for a contract) and its b256.
+fn parse_fee_withdrawal(payload: Bytes) -> FeeWithdrawal {
+    require(payload.len == 41, PythError::InvalidGovernanceMessage);
+    let amount = read_u64(payload, 0);
+    let recipient_type = payload.get(8).unwrap();
+    let (_, recipient) = payload.split_at(9);
+    let recipient: b256 = recipient.into();
+    let recipient = if recipient_type == 0 {
+        Identity::Address(Address::from(recipient))
+    } else {
+        require(recipient_type == 1, PythError::InvalidGovernanceMessage);
+        Identity::ContractId(ContractId::from(recipient))
+    };
+
+    FeeWithdrawal {
+        amount,
+        recipient,
+    }
+}
+
+fn read_u16(bytes: Bytes, index: u64) -> u16 {
+    u16::from_be_bytes([bytes.get(index).unwrap(), bytes.get(index + 1).unwrap()])
+}
+
+fn read_u64(bytes: Bytes, index: u64) -> u64 {
+    u64::from_be_bytes([
+        bytes.get(index).unwrap(),
+        bytes.get(index + 1).unwrap(),
+        bytes.get(index + 2).unwrap(),
+        bytes.get(index + 3).unwrap(),
+        bytes.get(index + 4).unwrap(),
+        bytes.get(index + 5).unwrap(),
+        bytes.get(index + 6).unwrap(),
+        bytes.get(index + 7).unwrap(),
+    ])
+}
@@ -1,14 +1,22 @@
 library;
 
 pub enum PythError {
+    /// Emitted when an excess payment has no single sender to be refunded to.
+    AmbiguousRefundRecipient: (),
     FeesCanOnlyBePaidInTheBaseAsset: (),
     GuardianSetNotFound: (),
     IncorrectMessageType: (),
     InsufficientFee: (),
+    InsufficientFeeBalance: (),
     InvalidArgument: (),
     InvalidAttestationSize: (),
     InvalidDataSourcesLength: (),
     InvalidExponent: (),
+    InvalidGovernanceAction: (),
+    InvalidGovernanceDataSource: (),
+    InvalidGovernanceMessage: (),
+    InvalidGovernanceModule: (),
+    InvalidGovernanceTarget: (),
     InvalidHeaderSize: (),
     InvalidMagic: (),
     InvalidMajorVersion: (),
@@ -24,6 +32,7 @@ pub enum PythError {
     LengthOfPriceFeedIdsAndPublishTimesMustMatch: (),
     NewGuardianSetIsEmpty: (),
     NumberOfUpdatesIrretrievable: (),
+    OldGovernanceMessage: (),
     /// Emitted when a Price's `publish_time` is stale.
     OutdatedPrice: (),
     /// Emitted when a PriceFeed could not be retrieved.
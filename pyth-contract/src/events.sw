@@ -2,7 +2,10 @@ library;
 
 use pyth_interface::data_structures::{
     data_source::DataSource,
-    price::PriceFeedId,
+    price::{
+        PriceFeed,
+        PriceFeedId,
+    },
     wormhole_light::WormholeProvider,
 };
 
@@ -10,12 +13,42 @@ pub struct ConstructedEvent {
     guardian_set_index: u32,
 }
 
+pub struct FeeRefundedEvent {
+    amount: u64,
+    recipient: Identity,
+}
+
+pub struct FeesWithdrawnEvent {
+    amount: u64,
+    recipient: Identity,
+}
+
 pub struct NewGuardianSetEvent {
     governance_action_hash: b256,
     // new_guardian_set: GuardianSet, // TODO: Uncomment when SDK supports logs with nested Vecs https://github.com/FuelLabs/fuels-rs/issues/1046
     new_guardian_set_index: u32,
 }
 
+pub struct PriceFeedUpdateEvent {
+    id: PriceFeedId,
+    publish_time: u64,
+    price: u64,
+    confidence: u64,
+    exponent: u32,
+}
+
+impl PriceFeedUpdateEvent {
+    pub fn new(price_feed: PriceFeed) -> Self {
+        Self {
+            id: price_feed.id,
+            publish_time: price_feed.price.publish_time,
+            price: price_feed.price.price,
+            confidence: price_feed.price.confidence,
+            exponent: price_feed.price.exponent,
+        }
+    }
+}
+
 pub struct UpdatedPriceFeedsEvent {
     updated_price_feeds: Vec<PriceFeedId>,
 }
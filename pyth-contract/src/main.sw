@@ -7,6 +7,7 @@ mod data_structures;
 mod events;
 
 use std::{
+    auth::msg_sender,
     block::timestamp,
     bytes::Bytes,
     call_frames::msg_asset_id,
@@ -14,25 +15,39 @@ use std::{
         BASE_ASSET_ID,
         ZERO_B256,
     },
-    context::msg_amount,
+    context::{
+        msg_amount,
+        this_balance,
+    },
     hash::Hash,
     storage::{
         storage_map::StorageMap,
         storage_vec::*,
     },
+    token::transfer,
     u256::U256,
 };
 
 use ::errors::{PythError, WormholeError};
-use ::utils::{difference, total_fee};
+use ::utils::{append, difference, total_fee};
 use ::data_structures::{
     batch_attestation_update::parse_and_verify_batch_attestation_header,
     data_source::*,
+    governance_instruction::{
+        GovernanceAction,
+        GovernanceInstruction,
+    },
     price::*,
     update_type::UpdateType,
     wormhole_light::*,
 };
-use ::events::{ConstructedEvent, NewGuardianSetEvent, UpdatedPriceFeedsEvent};
+use ::events::{
+    ConstructedEvent,
+    FeeRefundedEvent,
+    FeesWithdrawnEvent,
+    NewGuardianSetEvent,
+    UpdatedPriceFeedsEvent,
+};
 
 use pyth_interface::{
     data_structures::{
@@ -48,6 +63,7 @@ use pyth_interface::{
         },
     },
     PythCore,
+    PythGovernance,
     PythInfo,
     PythInit,
     WormholeGuardians,
@@ -64,9 +80,16 @@ storage {
     //   |                |
     // --+-- PYTH STATE --+--
     //   |                |
+    // Emitter of the governance VAAs accepted by execute_governance_instruction
+    governance_data_source: DataSource = DataSource {
+        chain_id: 0u16,
+        emitter_address: ZERO_B256,
+    },
     // (chainId, emitterAddress) => isValid; takes advantage of
     // constant-time mapping lookup for VM verification
     is_valid_data_source: StorageMap<DataSource, bool> = StorageMap {},
+    // Sequence of the last executed governance VAA, for replay protection
+    last_executed_governance_sequence: u64 = 0,
     // Mapping of cached price information
     // priceId => PriceInfo
     latest_price_feed: StorageMap<PriceFeedId, PriceFeed> = StorageMap {},
@@ -278,6 +301,8 @@ impl PythCore for Contract {
 
             i += 1;
         }
+
+        refund(msg_amount());
     }
 
     #[storage(read)]
@@ -326,6 +351,23 @@ fn price_unsafe(price_feed_id: PriceFeedId) -> Price {
     price_feed.unwrap().price
 }
 
+/// Transfers `amount` of the asset forwarded with the call back to its sender. Reverts when the
+/// sender is ambiguous, as for a transaction whose coin inputs have several owners, rather than keep
+/// `amount`.
+fn refund(amount: u64) {
+    if amount > 0 {
+        let sender = msg_sender();
+        require(sender.is_ok(), PythError::AmbiguousRefundRecipient);
+        let recipient = sender.unwrap();
+        transfer(recipient, msg_asset_id(), amount);
+
+        log(FeeRefundedEvent {
+            amount,
+            recipient,
+        });
+    }
+}
+
 #[storage(read)]
 fn update_fee(update_data: Vec<Bytes>) -> u64 {
     let mut total_number_of_updates = 0;
@@ -359,14 +401,14 @@ fn update_price_feeds(update_data: Vec<Bytes>) {
 
     let mut total_number_of_updates = 0;
 
-    // let mut updated_price_feeds: Vec<PriceFeedId> = Vec::new(); // TODO: requires append for Vec
+    let mut updated_price_feeds: Vec<PriceFeedId> = Vec::new();
     let mut i = 0;
     while i < update_data.len {
         let data = update_data.get(i).unwrap();
 
         match UpdateType::determine_type(data) {
             UpdateType::Accumulator(accumulator_update) => {
-                let (number_of_updates, _updated_ids) = accumulator_update.update_price_feeds(
+                let (number_of_updates, updated_ids) = accumulator_update.update_price_feeds(
                     current_guardian_set_index(),
                     storage
                         .wormhole_guardian_sets,
@@ -375,11 +417,11 @@ fn update_price_feeds(update_data: Vec<Bytes>) {
                     storage
                         .is_valid_data_source,
                 );
-                // updated_price_feeds.append(updated_ids); // TODO: requires append for Vec
+                append(updated_price_feeds, updated_ids);
                 total_number_of_updates += number_of_updates;
             },
             UpdateType::BatchAttestation(batch_attestation_update) => {
-                let _updated_ids = batch_attestation_update.update_price_feeds(
+                let updated_ids = batch_attestation_update.update_price_feeds(
                     current_guardian_set_index(),
                     storage
                         .wormhole_guardian_sets,
@@ -388,7 +430,7 @@ fn update_price_feeds(update_data: Vec<Bytes>) {
                     storage
                         .is_valid_data_source,
                 );
-                // updated_price_feeds.append(updated_ids); // TODO: requires append for Vec
+                append(updated_price_feeds, updated_ids);
                 total_number_of_updates += 1;
             },
         }
@@ -399,9 +441,11 @@ fn update_price_feeds(update_data: Vec<Bytes>) {
     let required_fee = total_fee(total_number_of_updates, storage.single_update_fee);
     require(msg_amount() >= required_fee, PythError::InsufficientFee);
 
-    // log(UpdatedPriceFeedsEvent { // TODO: requires append for Vec
-    //     updated_price_feeds,
-    // })
+    log(UpdatedPriceFeedsEvent {
+        updated_price_feeds,
+    });
+
+    refund(msg_amount() - required_fee);
 }
 
 #[storage(read)]
@@ -413,6 +457,7 @@ impl PythInit for Contract {
     #[storage(read, write)]
     fn constructor(
         data_sources: Vec<DataSource>,
+        governance_data_source: DataSource,
         single_update_fee: u64,
         valid_time_period_seconds: u64,
         wormhole_guardian_set_upgrade: Bytes,
@@ -420,16 +465,8 @@ impl PythInit for Contract {
         initialize_ownership(DEPLOYER);
         only_owner();
 
-        require(data_sources.len > 0, PythError::InvalidDataSourcesLength);
-
-        let mut i = 0;
-        while i < data_sources.len {
-            let data_source = data_sources.get(i).unwrap();
-            storage.is_valid_data_source.insert(data_source, true);
-            storage.valid_data_sources.push(data_source);
-
-            i += 1;
-        }
+        set_data_sources(data_sources);
+        storage.governance_data_source.write(governance_data_source);
 
         storage
             .valid_time_period_seconds
@@ -460,7 +497,110 @@ impl PythInit for Contract {
     }
 }
 
+impl PythGovernance for Contract {
+    #[storage(read)]
+    fn governance_data_source() -> DataSource {
+        storage.governance_data_source.read()
+    }
+
+    #[storage(read)]
+    fn last_executed_governance_sequence() -> u64 {
+        storage.last_executed_governance_sequence.read()
+    }
+
+    #[storage(read, write)]
+    fn execute_governance_instruction(encoded_vm: Bytes) {
+        execute_governance_instruction(encoded_vm)
+    }
+}
+
+/// PythGovernance Private Functions ///
+#[storage(read, write)]
+fn execute_governance_instruction(encoded_vm: Bytes) {
+    let vm = WormholeVM::parse_and_verify_wormhole_vm(
+        current_guardian_set_index(),
+        encoded_vm,
+        storage
+            .wormhole_guardian_sets,
+    );
+    let governance_data_source = storage.governance_data_source.read();
+    require(
+        vm.emitter_chain_id == governance_data_source.chain_id && vm.emitter_address == governance_data_source.emitter_address,
+        PythError::InvalidGovernanceDataSource,
+    );
+    require(
+        vm.sequence > storage.last_executed_governance_sequence.read(),
+        PythError::OldGovernanceMessage,
+    );
+    require(
+        governance_action_is_consumed(vm.governance_action_hash) == false,
+        WormholeError::GovernanceActionAlreadyConsumed,
+    );
+
+    let instruction = GovernanceInstruction::parse(vm.payload);
+
+    storage
+        .wormhole_consumed_governance_actions
+        .insert(vm.governance_action_hash, true);
+    storage.last_executed_governance_sequence.write(vm.sequence);
+
+    match instruction.action {
+        GovernanceAction::SetDataSources(data_sources) => {
+            let mut i = 0;
+            while i < storage.valid_data_sources.len() {
+                let data_source = storage.valid_data_sources.get(i).unwrap().read();
+                storage.is_valid_data_source.insert(data_source, false);
+
+                i += 1;
+            }
+            storage.valid_data_sources.clear();
+
+            set_data_sources(data_sources);
+        },
+        GovernanceAction::SetFee(single_update_fee) => {
+            storage.single_update_fee.write(single_update_fee);
+        },
+        GovernanceAction::SetValidPeriod(valid_time_period_seconds) => {
+            storage
+                .valid_time_period_seconds
+                .write(valid_time_period_seconds);
+        },
+        GovernanceAction::WithdrawFees(withdrawal) => {
+            require(
+                withdrawal
+                    .amount <= this_balance(BASE_ASSET_ID),
+                PythError::InsufficientFeeBalance,
+            );
+            transfer(withdrawal.recipient, BASE_ASSET_ID, withdrawal.amount);
+
+            log(FeesWithdrawnEvent {
+                amount: withdrawal.amount,
+                recipient: withdrawal.recipient,
+            });
+        },
+    }
+}
+
+/// Adds `data_sources` to the valid data sources.
+#[storage(read, write)]
+fn set_data_sources(data_sources: Vec<DataSource>) {
+    require(data_sources.len > 0, PythError::InvalidDataSourcesLength);
+
+    let mut i = 0;
+    while i < data_sources.len {
+        let data_source = data_sources.get(i).unwrap();
+        storage.is_valid_data_source.insert(data_source, true);
+        storage.valid_data_sources.push(data_source);
+
+        i += 1;
+    }
+}
+
 impl PythInfo for Contract {
+    fn fee_balance() -> u64 {
+        this_balance(BASE_ASSET_ID)
+    }
+
     #[storage(read)]
     fn valid_data_sources() -> Vec<DataSource> {
         storage.valid_data_sources.load_vec()
@@ -16,12 +16,28 @@ fn leaf_hash(data: Bytes) -> Bytes {
     slice
 }
 
+// `b256::from(Bytes)` always reads 32 bytes, so a 20 byte digest is zero padded before converting
+fn digest_to_b256(digest: Bytes) -> b256 {
+    let mut padded = Bytes::with_capacity(32);
+    let mut i = 0;
+    while i < 32 {
+        if i < digest.len {
+            padded.push(digest.get(i).unwrap());
+        } else {
+            padded.push(0u8);
+        }
+        i += 1;
+    }
+
+    padded.into()
+}
+
 fn node_hash(child_a: Bytes, child_b: Bytes) -> Bytes {
     let mut bytes = Bytes::with_capacity(41);
     bytes.push(MERKLE_NODE_PREFIX);
 
-    let a: b256 = child_a.into();
-    let b: b256 = child_b.into();
+    let a = digest_to_b256(child_a);
+    let b = digest_to_b256(child_b);
     if a > b {
         bytes.append(child_b);
         bytes.append(child_a);
@@ -57,8 +73,7 @@ pub fn validate_proof(
         i += 1;
     }
 
-    // TODO: investigate failing require statement on the accumulator update path.
-    // require(current_digest == root, PythError::InvalidProof);
+    require(current_digest == root, PythError::InvalidProof);
 
     proof_offset
 }
@@ -4,6 +4,15 @@ pub fn difference(x: u64, y: u64) -> u64 {
     if x > y { x - y } else { y - x }
 }
 
+/// Pushes every element of `other` onto `vec`, as the standard library has no `Vec::append`.
+pub fn append<T>(ref mut vec: Vec<T>, other: Vec<T>) {
+    let mut i = 0;
+    while i < other.len {
+        vec.push(other.get(i).unwrap());
+        i += 1;
+    }
+}
+
 pub fn absolute_of_exponent(exponent: u32) -> u32 {
     if exponent == 0u32 {
         exponent
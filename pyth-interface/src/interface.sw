@@ -197,7 +197,9 @@ abi PythCore {
     /// # Additional Information
     ///
     /// This function requires the caller to pay a fee in wei; the required fee can be computed by calling
-    /// `update_fee`.
+    /// `update_fee`. Any payment in excess of the required fee is refunded to the caller, which requires a
+    /// variable output in the transaction. A transaction whose coin inputs have several owners has no single
+    /// caller to refund, and reverts when paying in excess.
     /// Prices will be updated if they are more recent than the current stored prices.
     /// The call will succeed even if the update is not the most recent.
     ///
@@ -209,6 +211,7 @@ abi PythCore {
     ///
     /// * When the transferred fee is not sufficient.
     /// * When the `update_data` is invalid.
+    /// * When refunding an excess payment to an ambiguous caller.
     #[storage(read, write), payable]
     fn update_price_feeds(update_data: Vec<Bytes>);
 
@@ -224,8 +227,10 @@ abi PythCore {
     ///
     /// `price_feed_ids` and `publish_times` are two arrays with the same size that correspond to senders known `publish_time`
     /// of each PriceFeedId when calling this method. If all of price feeds within `price_feed_ids` have updated and have
-    /// a newer or equal publish time than the given publish time, it will reject the transaction to save gas.
-    /// Otherwise, it calls `update_price_feeds` to update the prices.
+    /// a newer or equal publish time than the given publish time, it refunds the whole payment to the caller without
+    /// parsing `update_data` to save gas. Otherwise, it calls `update_price_feeds` to update the prices.
+    /// As in `update_price_feeds`, a refund requires a variable output in the transaction and reverts when the
+    /// transaction's coin inputs have several owners.
     ///
     /// # Arguments
     ///
@@ -236,9 +241,9 @@ abi PythCore {
     ///
     /// # Reverts
     ///
-    /// * When update is not necessary.
     /// * When the transferred fee is not sufficient.
     /// * When the `update_data` is invalid.
+    /// * When refunding the payment to an ambiguous caller.
     #[storage(read, write), payable]
     fn update_price_feeds_if_necessary(
         price_feed_ids: Vec<PriceFeedId>,
@@ -259,13 +264,61 @@ abi PythInit {
     #[storage(read, write)]
     fn constructor(
         data_sources: Vec<DataSource>,
+        governance_data_source: DataSource,
         single_update_fee: u64,
         valid_time_period_seconds: u64,
         wormhole_guardian_set_upgrade: Bytes,
     );
 }
 
+abi PythGovernance {
+    /// This function returns the emitter whose governance VAAs the contract accepts.
+    ///
+    /// # Returns
+    ///
+    /// * [DataSource] - The governance data source set in the `constructor`.
+    #[storage(read)]
+    fn governance_data_source() -> DataSource;
+
+    /// This function returns the sequence of the last executed governance VAA.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The sequence, or 0 if no governance instruction has been executed.
+    #[storage(read)]
+    fn last_executed_governance_sequence() -> u64;
+
+    /// This function executes a Pyth governance instruction (PTGM) signed by the current guardian set.
+    ///
+    /// # Additional Information
+    ///
+    /// Supports the `SetDataSources`, `SetFee`, `SetValidPeriod` and `WithdrawFees` actions targeting every chain (target chain id 0).
+    /// `WithdrawFees` transfers base asset fees out of the contract, which has no owner once constructed.
+    ///
+    /// # Arguments
+    ///
+    /// * `encoded_vm`: [Bytes] - The Wormhole VAA carrying the governance instruction.
+    ///
+    /// # Reverts
+    ///
+    /// * When the VAA is not signed by the current guardian set.
+    /// * When the VAA is not emitted by the governance data source.
+    /// * When the VAA sequence is not greater than the last executed governance sequence.
+    /// * When the VAA has already been consumed.
+    /// * When the instruction is malformed, targets another module or chain, or has an unsupported action.
+    /// * When a `WithdrawFees` amount exceeds the contract's base asset balance.
+    #[storage(read, write)]
+    fn execute_governance_instruction(encoded_vm: Bytes);
+}
+
 abi PythInfo {
+    /// This function returns the update fees held by the contract.
+    ///
+    /// # Returns
+    ///
+    /// * [u64] - The contract's base asset balance.
+    fn fee_balance() -> u64;
+
     #[storage(read)]
     fn latest_publish_time(price_feed_id: PriceFeedId) -> u64;
 
@@ -1,63 +1,158 @@
 use fuels::{
-    prelude::{Address, Provider, WalletUnlocked},
-    types::Bits256,
+    accounts::ViewOnlyAccount,
+    prelude::{Address, Provider, WalletUnlocked, BASE_ASSET_ID},
+    types::{Bits256, ContractId},
 };
-use pyth_sdk::{constants::BETA_5_URL, pyth_utils::guardian_set_upgrade_4_vaa};
 use pyth_sdk::{
-    constants::{
-        BTC_USD_PRICE_FEED_ID, DEFAULT_VALID_TIME_PERIOD, ETH_USD_PRICE_FEED_ID,
-        USDC_USD_PRICE_FEED_ID,
+    deploy::{
+        dry_run_deployment, verify_bytecode, verify_deployment, ConstructorManifest, DeployConfig,
+        DeploymentManifest, DeploymentTransactions, VerificationReport, DEFAULT_MANIFEST_PATH,
     },
-    pyth_utils::{update_data_bytes, Pyth},
+    pyth_utils::Pyth,
 };
+use rand::Rng;
+use std::path::PathBuf;
+
+const USAGE: &str = "usage: deploy_pyth [--config <deploy.toml>] [--dry-run]
+       deploy_pyth verify [<deployment.json>]";
 
 #[tokio::main]
 async fn main() {
     dotenv::dotenv().ok();
 
-    println!("🔮 Testnet Pyth deploy action");
+    let mut args = std::env::args().skip(1).peekable();
+    if args.peek().map(String::as_str) == Some("verify") {
+        args.next();
+        verify(args).await;
+    } else {
+        deploy(args).await;
+    }
+}
+
+async fn deploy(mut args: impl Iterator<Item = String>) {
+    println!("🔮 Pyth deploy action");
+
+    let mut config_path = None;
+    let mut dry_run = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => config_path = Some(PathBuf::from(args.next().expect(USAGE))),
+            "--dry-run" => dry_run = true,
+            _ => panic!("unexpected argument `{arg}`\n{USAGE}"),
+        }
+    }
+    let config = match config_path {
+        Some(path) => DeployConfig::from_file(&path).unwrap(),
+        None => DeployConfig::default(),
+    };
+    let network = config.network_config().unwrap();
+    let upgrade = network.guardian_set_upgrade().unwrap();
 
-    let provider = Provider::connect(BETA_5_URL).await.unwrap();
+    let provider = Provider::connect(&network.provider_url).await.unwrap();
 
     let admin_pk = std::env::var("ADMIN").expect("ADMIN environment variable missing");
-    let admin =
-        WalletUnlocked::new_from_private_key(admin_pk.parse().unwrap(), Some(provider.clone()));
-    println!("Admin address = 0x{}\n", Address::from(admin.address()));
-
-    let pyth = Pyth::deploy(admin).await.unwrap();
-
-    let _ = pyth
-        .constructor(DEFAULT_VALID_TIME_PERIOD, guardian_set_upgrade_4_vaa())
-        .await
-        .unwrap();
-
-    //check GS
-    let gsi = pyth.current_guardian_set_index().await.unwrap().value;
-    println!("gsi: {:?}", gsi);
-
-    let update_data = update_data_bytes(None).await.unwrap();
-    let fee = pyth.update_fee(&update_data).await.unwrap().value;
-
-    //print fee
-    println!("fee: {:?}", fee);
-
-    let btc_price_feed = Bits256::from_hex_str(BTC_USD_PRICE_FEED_ID).unwrap();
-    let eth_price_feed = Bits256::from_hex_str(ETH_USD_PRICE_FEED_ID).unwrap();
-    let usdc_price_feed = Bits256::from_hex_str(USDC_USD_PRICE_FEED_ID).unwrap();
-
-    let _ = pyth.update_price_feeds(fee, &update_data).await.unwrap();
-
-    println!("Pyth address = 0x{:?}\n", pyth.instance.contract_id().hash);
-    println!(
-        "BTC price {:?}",
-        pyth.price(btc_price_feed).await.unwrap().value
-    );
-    println!(
-        "ETH price {:?}",
-        pyth.price(eth_price_feed).await.unwrap().value
-    );
-    println!(
-        "USDC price {:?}",
-        pyth.price(usdc_price_feed).await.unwrap().value
-    );
+    let admin = WalletUnlocked::new_from_private_key(admin_pk.parse().unwrap(), Some(provider));
+    let admin_address = Address::from(admin.address());
+    println!("Admin address = 0x{admin_address}\n");
+
+    let salt = config
+        .salt
+        .map(|salt| salt.0)
+        .unwrap_or_else(|| rand::thread_rng().gen());
+    let contract_id = Pyth::deployment_contract_id(admin_address, salt).unwrap();
+
+    let mut transactions = DeploymentTransactions::default();
+    if dry_run {
+        let balance = admin.get_asset_balance(&BASE_ASSET_ID).await.unwrap();
+        dry_run_deployment(&admin, salt).await.unwrap();
+        println!("Dry run, no transaction is sent");
+        println!("The constructor cannot be dry run before the contract exists, only its guardian set upgrade VAA was decoded");
+        println!("Admin balance = {balance}");
+    } else {
+        let (pyth, create_tx_id) = Pyth::deploy_with_salt_and_tx_id(admin, salt).await.unwrap();
+        transactions.create = Some(Bits256(*create_tx_id));
+
+        let response = pyth.constructor_with_network(&network).await.unwrap();
+        transactions.constructor = response.response.tx_id.map(|tx_id| Bits256(*tx_id));
+
+        let guardian_set_index = pyth.current_guardian_set_index().await.unwrap().value;
+        assert_eq!(guardian_set_index, upgrade.new_guardian_set_index);
+    }
+    println!("Pyth address = 0x{contract_id}");
+    println!("Guardian set index = {}\n", upgrade.new_guardian_set_index);
+
+    let manifest = DeploymentManifest {
+        network: network.name.clone(),
+        provider_url: network.provider_url.clone(),
+        dry_run,
+        contract_id: Bits256(*contract_id),
+        deployer: Bits256(*admin_address),
+        salt: Bits256(salt),
+        transactions,
+        constructor: ConstructorManifest {
+            data_sources: network.data_sources.0.clone(),
+            governance_data_source: network.governance_data_source,
+            single_update_fee: network.single_update_fee,
+            valid_time_period_seconds: network.valid_time_period_seconds,
+            wormhole_guardian_set_upgrade: hex::encode(&network.guardian_set_upgrade_vaa.0),
+            guardian_set_index: upgrade.new_guardian_set_index,
+        },
+    }
+    .to_json()
+    .unwrap();
+
+    // a dry run leaves the manifest of an earlier deployment in place
+    if dry_run {
+        println!("{manifest}");
+    } else {
+        std::fs::write(&config.manifest, manifest).unwrap();
+        println!(
+            "Deployment manifest written to {}",
+            config.manifest.display()
+        );
+    }
+}
+
+async fn verify(mut args: impl Iterator<Item = String>) {
+    println!("🔮 Pyth deployment verification");
+
+    let manifest_path = args
+        .next()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(DEFAULT_MANIFEST_PATH));
+    if let Some(arg) = args.next() {
+        panic!("unexpected argument `{arg}`\n{USAGE}");
+    }
+    let manifest =
+        DeploymentManifest::from_json(&std::fs::read_to_string(&manifest_path).unwrap()).unwrap();
+    if manifest.dry_run {
+        panic!("{} is the manifest of a dry run", manifest_path.display());
+    }
+
+    let provider = Provider::connect(&manifest.provider_url).await.unwrap();
+    let contract_id = ContractId::from(manifest.contract_id.0);
+    let pyth = match Pyth::connect_readonly(contract_id, provider.clone()).await {
+        Ok(pyth) => pyth,
+        Err(error) => {
+            // report what is deployed at the address, if anything, before failing
+            let deployer = Address::from(manifest.deployer.0);
+            let check = verify_bytecode(&provider, contract_id, deployer)
+                .await
+                .unwrap();
+            println!(
+                "{}",
+                VerificationReport {
+                    checks: vec![check]
+                }
+            );
+            panic!("{error}");
+        }
+    };
+    println!("Pyth address = 0x{}\n", hex::encode(manifest.contract_id.0));
+
+    let report = verify_deployment(&pyth, &manifest).await.unwrap();
+    println!("{report}");
+    if !report.passed() {
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,67 @@
+use fuels::prelude::{Address, Provider, WalletUnlocked};
+use pyth_sdk::{
+    hermes::{HermesClient, HermesConfig},
+    keeper::{router, Keeper, KeeperConfig, KeeperMetrics, KeeperStep},
+    pyth_utils::Pyth,
+};
+use std::sync::Arc;
+
+#[tokio::main]
+async fn main() {
+    dotenv::dotenv().ok();
+
+    println!("🔮 Pyth keeper");
+
+    let config_path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "keeper.json".to_string());
+    let config = KeeperConfig::from_json(&std::fs::read_to_string(&config_path).unwrap()).unwrap();
+
+    let provider = Provider::connect(&config.provider_url).await.unwrap();
+
+    let keeper_pk = std::env::var("KEEPER").expect("KEEPER environment variable missing");
+    let wallet = WalletUnlocked::new_from_private_key(keeper_pk.parse().unwrap(), Some(provider));
+    println!("Keeper address = 0x{}\n", Address::from(wallet.address()));
+
+    let pyth = Pyth::connect(config.contract_id, wallet).await.unwrap();
+    let hermes =
+        HermesClient::new(HermesConfig::default().with_base_url(&config.hermes_url)).unwrap();
+    let keeper = Keeper::new(pyth, hermes, config.feeds.clone());
+
+    let metrics = Arc::new(KeeperMetrics::new().unwrap());
+    tokio::spawn(
+        axum::Server::bind(&config.metrics_address)
+            .serve(router(metrics.clone()).into_make_service()),
+    );
+    println!("Serving metrics on {}\n", config.metrics_address);
+
+    loop {
+        let step = keeper.step().await;
+        if let Ok(step) = &step {
+            metrics.record_step(step);
+        }
+        match step {
+            Ok(KeeperStep::UpToDate) => {}
+            Ok(KeeperStep::Updated { updates, fee }) => {
+                println!("updated {} price feeds, fee: {fee}", updates.len());
+            }
+            Ok(KeeperStep::InsufficientBalance { balance, fee }) => {
+                eprintln!("insufficient balance {balance} for the update fee {fee}");
+            }
+            Ok(KeeperStep::Reverted { updates, reason }) => {
+                eprintln!("update of {} price feeds reverted: {reason}", updates.len());
+            }
+            Err(error) => eprintln!("keeper step failed: {error}"),
+        }
+
+        match keeper.observe().await {
+            Ok(observation) => metrics.record_observation(&observation),
+            Err(error) => {
+                eprintln!("keeper observation failed: {error}");
+                metrics.record_observation_error(&error);
+            }
+        }
+
+        tokio::time::sleep(config.poll_interval()).await;
+    }
+}
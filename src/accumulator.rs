@@ -0,0 +1,334 @@
+use crate::{
+    decode::{contract_price, DecodeError, Reader},
+    pyth_utils::PriceFeed,
+    wormhole::Vaa,
+};
+use fuels::types::{Bits256, Bytes};
+use sha3::{Digest, Keccak256};
+
+pub const ACCUMULATOR_MAGIC: [u8; 4] = *b"PNAU";
+pub const ACCUMULATOR_WORMHOLE_MAGIC: [u8; 4] = *b"AUWV";
+pub const MAJOR_VERSION: u8 = 1;
+pub const MERKLE_LEAF_PREFIX: u8 = 0;
+pub const MERKLE_NODE_PREFIX: u8 = 1;
+
+const WORMHOLE_MERKLE_UPDATE_TYPE: u8 = 0;
+const PRICE_FEED_MESSAGE_TYPE: u8 = 0;
+
+/// A decoded accumulator (`PNAU`) update.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccumulatorUpdate {
+    pub major_version: u8,
+    pub minor_version: u8,
+    pub trailing_header: Vec<u8>,
+    /// The guardian signed VAA whose payload commits to `merkle_root`.
+    pub vaa: Vaa,
+    pub merkle_root: WormholeMerkleRoot,
+    pub updates: Vec<MerklePriceUpdate>,
+}
+
+/// The `AUWV` payload of an accumulator update's VAA.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WormholeMerkleRoot {
+    pub slot: u64,
+    pub ring_size: u32,
+    pub digest: [u8; 20],
+}
+
+/// A price message together with its Merkle path to the signed digest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerklePriceUpdate {
+    /// The message as encoded in the update, the Merkle leaf is computed over these bytes.
+    pub encoded_message: Vec<u8>,
+    pub message: PriceFeedMessage,
+    pub proof: Vec<[u8; 20]>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceFeedMessage {
+    pub id: Bits256,
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+    pub prev_publish_time: i64,
+    pub ema_price: i64,
+    pub ema_conf: u64,
+}
+
+impl AccumulatorUpdate {
+    /// Whether `data` is an accumulator update rather than a batch attestation VAA.
+    pub fn is_accumulator_update(data: &[u8]) -> bool {
+        data.len() > 4 && data[..4] == ACCUMULATOR_MAGIC
+    }
+
+    pub fn parse(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = Reader::new(data);
+
+        reader.magic(ACCUMULATOR_MAGIC)?;
+        let major_version = reader.u8()?;
+        if major_version != MAJOR_VERSION {
+            return Err(DecodeError::InvalidMajorVersion(major_version.into()));
+        }
+        // every minor version is backwards compatible
+        let minor_version = reader.u8()?;
+        let trailing_header_size = reader.u8()?;
+        let trailing_header = reader.bytes(trailing_header_size.into())?.to_vec();
+        let update_type = reader.u8()?;
+        if update_type != WORMHOLE_MERKLE_UPDATE_TYPE {
+            return Err(DecodeError::InvalidUpdateType(update_type));
+        }
+
+        let vaa_size = reader.u16()?;
+        let vaa = Vaa::parse(reader.bytes(vaa_size.into())?)?;
+        let merkle_root = WormholeMerkleRoot::parse(&vaa.payload)?;
+
+        let number_of_updates = reader.u8()?;
+        let updates = (0..number_of_updates)
+            .map(|_| MerklePriceUpdate::read(&mut reader))
+            .collect::<Result<Vec<MerklePriceUpdate>, DecodeError>>()?;
+        reader.finish()?;
+
+        Ok(Self {
+            major_version,
+            minor_version,
+            trailing_header,
+            vaa,
+            merkle_root,
+            updates,
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let vaa = self.vaa.encode();
+
+        let mut data = ACCUMULATOR_MAGIC.to_vec();
+        data.push(self.major_version);
+        data.push(self.minor_version);
+        data.push(self.trailing_header.len() as u8);
+        data.extend(&self.trailing_header);
+        data.push(WORMHOLE_MERKLE_UPDATE_TYPE);
+        data.extend((vaa.len() as u16).to_be_bytes());
+        data.extend(vaa);
+        data.push(self.updates.len() as u8);
+        for update in &self.updates {
+            data.extend((update.encoded_message.len() as u16).to_be_bytes());
+            data.extend(&update.encoded_message);
+            data.push(update.proof.len() as u8);
+            for sibling in &update.proof {
+                data.extend(sibling);
+            }
+        }
+
+        data
+    }
+
+    /// Keeps only the updates of `price_feed_ids`. The header and VAA are left unchanged and every
+    /// kept message still has its own proof, so the update remains valid.
+    pub fn retain_price_feeds(&mut self, price_feed_ids: &[Bits256]) {
+        self.updates
+            .retain(|update| price_feed_ids.contains(&update.message.id));
+    }
+
+    /// The price feeds as the contract would store them.
+    pub fn price_feeds(&self) -> Vec<PriceFeed> {
+        self.updates
+            .iter()
+            .map(|update| update.message.to_price_feed())
+            .collect()
+    }
+}
+
+impl WormholeMerkleRoot {
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = Reader::new(payload);
+
+        reader.magic(ACCUMULATOR_WORMHOLE_MAGIC)?;
+        let update_type = reader.u8()?;
+        if update_type != WORMHOLE_MERKLE_UPDATE_TYPE {
+            return Err(DecodeError::InvalidUpdateType(update_type));
+        }
+
+        Ok(Self {
+            slot: reader.u64()?,
+            ring_size: reader.u32()?,
+            digest: reader.array()?,
+        })
+    }
+}
+
+impl WormholeMerkleRoot {
+    /// Encodes the root as the payload of an accumulator update's VAA.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut payload = ACCUMULATOR_WORMHOLE_MAGIC.to_vec();
+        payload.push(WORMHOLE_MERKLE_UPDATE_TYPE);
+        payload.extend(self.slot.to_be_bytes());
+        payload.extend(self.ring_size.to_be_bytes());
+        payload.extend(self.digest);
+
+        payload
+    }
+}
+
+impl MerklePriceUpdate {
+    /// Builds the update of every message, with proofs of a Merkle tree over all of them, and
+    /// returns the tree's root along with them.
+    ///
+    /// The leaves are padded to a power of two with zeroed digests.
+    pub fn tree(messages: &[PriceFeedMessage]) -> ([u8; 20], Vec<Self>) {
+        let encoded_messages: Vec<Vec<u8>> =
+            messages.iter().map(PriceFeedMessage::encode).collect();
+
+        let mut layer: Vec<[u8; 20]> = encoded_messages
+            .iter()
+            .map(|message| merkle_hash(MERKLE_LEAF_PREFIX, &[message]))
+            .collect();
+        layer.resize(layer.len().next_power_of_two().max(1), [0u8; 20]);
+
+        let mut proofs = vec![vec![]; messages.len()];
+        let mut positions: Vec<usize> = (0..messages.len()).collect();
+        while layer.len() > 1 {
+            for (proof, position) in proofs.iter_mut().zip(positions.iter_mut()) {
+                proof.push(layer[*position ^ 1]);
+                *position /= 2;
+            }
+            layer = layer
+                .chunks(2)
+                .map(|pair| {
+                    let (first, second) = if pair[0] < pair[1] {
+                        (pair[0], pair[1])
+                    } else {
+                        (pair[1], pair[0])
+                    };
+                    merkle_hash(MERKLE_NODE_PREFIX, &[&first, &second])
+                })
+                .collect();
+        }
+
+        let updates = encoded_messages
+            .into_iter()
+            .zip(messages)
+            .zip(proofs)
+            .map(|((encoded_message, message), proof)| Self {
+                encoded_message,
+                message: *message,
+                proof,
+            })
+            .collect();
+
+        (layer[0], updates)
+    }
+
+    fn read(reader: &mut Reader) -> Result<Self, DecodeError> {
+        let message_size = reader.u16()?;
+        let encoded_message = reader.bytes(message_size.into())?.to_vec();
+        let message = PriceFeedMessage::parse(&encoded_message)?;
+        let proof_size = reader.u8()?;
+        let proof = (0..proof_size)
+            .map(|_| reader.array())
+            .collect::<Result<Vec<[u8; 20]>, DecodeError>>()?;
+
+        Ok(Self {
+            encoded_message,
+            message,
+            proof,
+        })
+    }
+
+    /// The root the Merkle path resolves to, equal to the signed digest for a valid update.
+    pub fn merkle_root(&self) -> [u8; 20] {
+        self.proof.iter().fold(
+            merkle_hash(MERKLE_LEAF_PREFIX, &[&self.encoded_message]),
+            |current, sibling| {
+                let (first, second) = if current < *sibling {
+                    (current, *sibling)
+                } else {
+                    (*sibling, current)
+                };
+                merkle_hash(MERKLE_NODE_PREFIX, &[&first, &second])
+            },
+        )
+    }
+}
+
+impl PriceFeedMessage {
+    pub fn parse(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = Reader::new(data);
+
+        let message_type = reader.u8()?;
+        if message_type != PRICE_FEED_MESSAGE_TYPE {
+            return Err(DecodeError::InvalidMessageType(message_type));
+        }
+
+        // newer message versions may append fields, which are ignored like the contract does
+        Ok(Self {
+            id: Bits256(reader.array()?),
+            price: reader.i64()?,
+            conf: reader.u64()?,
+            exponent: reader.i32()?,
+            publish_time: reader.i64()?,
+            prev_publish_time: reader.i64()?,
+            ema_price: reader.i64()?,
+            ema_conf: reader.u64()?,
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = vec![PRICE_FEED_MESSAGE_TYPE];
+        data.extend(self.id.0);
+        data.extend(self.price.to_be_bytes());
+        data.extend(self.conf.to_be_bytes());
+        data.extend(self.exponent.to_be_bytes());
+        data.extend(self.publish_time.to_be_bytes());
+        data.extend(self.prev_publish_time.to_be_bytes());
+        data.extend(self.ema_price.to_be_bytes());
+        data.extend(self.ema_conf.to_be_bytes());
+
+        data
+    }
+
+    /// The price feed as the contract would store it.
+    pub fn to_price_feed(&self) -> PriceFeed {
+        PriceFeed {
+            ema_price: contract_price(
+                self.ema_price,
+                self.ema_conf,
+                self.exponent,
+                self.publish_time,
+            ),
+            id: self.id,
+            price: contract_price(self.price, self.conf, self.exponent, self.publish_time),
+        }
+    }
+}
+
+/// Trims every accumulator update in `update_data` to the messages of `price_feed_ids`, lowering
+/// the fee, which is charged per message. Batch attestation update data is returned unchanged.
+pub fn trim_update_data(
+    update_data: &[Bytes],
+    price_feed_ids: &[Bits256],
+) -> Result<Vec<Bytes>, DecodeError> {
+    update_data
+        .iter()
+        .map(|data| {
+            if !AccumulatorUpdate::is_accumulator_update(&data.0) {
+                return Ok(data.clone());
+            }
+
+            let mut update = AccumulatorUpdate::parse(&data.0)?;
+            update.retain_price_feeds(price_feed_ids);
+
+            Ok(Bytes(update.encode()))
+        })
+        .collect()
+}
+
+pub(crate) fn merkle_hash(prefix: u8, data: &[&[u8]]) -> [u8; 20] {
+    let mut hasher = Keccak256::new();
+    hasher.update([prefix]);
+    for data in data {
+        hasher.update(data);
+    }
+
+    hasher.finalize()[..20].try_into().unwrap()
+}
@@ -0,0 +1,209 @@
+use crate::{
+    decode::{contract_price, DecodeError, Reader},
+    pyth_utils::PriceFeed,
+    wormhole::Vaa,
+};
+use fuels::types::Bits256;
+
+pub const BATCH_MAGIC: [u8; 4] = *b"P2WH";
+pub const MAJOR_VERSION: u16 = 3;
+
+const BATCH_PAYLOAD_ID: u8 = 2;
+// size of the fields of a version 3 attestation, newer versions may append fields
+const ATTESTATION_SIZE: u16 = 149;
+
+/// A decoded batch price attestation (`P2WH`) update.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BatchAttestationUpdate {
+    pub vaa: Vaa,
+    pub major_version: u16,
+    pub minor_version: u16,
+    /// Header bytes following the payload id, unknown to this version.
+    pub trailing_header: Vec<u8>,
+    /// Size of each attestation, which may exceed the fields decoded into [PriceAttestation].
+    pub attestation_size: u16,
+    pub attestations: Vec<PriceAttestation>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PriceStatus {
+    /// The price feed is not currently updating for an unknown reason.
+    Unknown,
+    /// The price feed is updating as expected.
+    Trading,
+    /// The price feed is not currently updating because trading in the product has been halted.
+    Halted,
+    /// The price feed is not currently updating because an auction is setting the price.
+    Auction,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceAttestation {
+    pub product_id: Bits256,
+    pub price_id: Bits256,
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub ema_price: i64,
+    pub ema_conf: u64,
+    pub status: PriceStatus,
+    pub num_publishers: u32,
+    pub max_num_publishers: u32,
+    pub attestation_time: i64,
+    pub publish_time: i64,
+    pub prev_publish_time: i64,
+    pub prev_price: i64,
+    pub prev_conf: u64,
+}
+
+impl BatchAttestationUpdate {
+    pub fn parse(data: &[u8]) -> Result<Self, DecodeError> {
+        let vaa = Vaa::parse(data)?;
+        let mut reader = Reader::new(&vaa.payload);
+
+        reader.magic(BATCH_MAGIC)?;
+        let major_version = reader.u16()?;
+        if major_version != MAJOR_VERSION {
+            return Err(DecodeError::InvalidMajorVersion(major_version));
+        }
+        let minor_version = reader.u16()?;
+        let header_size = reader.u16()?;
+        let mut header = Reader::new(reader.bytes(header_size.into())?);
+        let payload_id = header.u8()?;
+        if payload_id != BATCH_PAYLOAD_ID {
+            return Err(DecodeError::InvalidPayloadId(payload_id));
+        }
+        let trailing_header = header.rest().to_vec();
+
+        let number_of_attestations = reader.u16()?;
+        let attestation_size = reader.u16()?;
+        if attestation_size < ATTESTATION_SIZE {
+            return Err(DecodeError::InvalidAttestationSize(attestation_size));
+        }
+        let attestations = (0..number_of_attestations)
+            .map(|_| PriceAttestation::parse(reader.bytes(attestation_size.into())?))
+            .collect::<Result<Vec<PriceAttestation>, DecodeError>>()?;
+        reader.finish()?;
+
+        Ok(Self {
+            vaa,
+            major_version,
+            minor_version,
+            trailing_header,
+            attestation_size,
+            attestations,
+        })
+    }
+
+    /// Encodes `attestations` as the payload of a batch attestation VAA.
+    pub fn encode_payload(attestations: &[PriceAttestation]) -> Vec<u8> {
+        let mut payload = BATCH_MAGIC.to_vec();
+        payload.extend(MAJOR_VERSION.to_be_bytes());
+        payload.extend(0u16.to_be_bytes());
+        // the header only holds the payload id
+        payload.extend(1u16.to_be_bytes());
+        payload.push(BATCH_PAYLOAD_ID);
+        payload.extend((attestations.len() as u16).to_be_bytes());
+        payload.extend(ATTESTATION_SIZE.to_be_bytes());
+        for attestation in attestations {
+            payload.extend(attestation.encode());
+        }
+
+        payload
+    }
+
+    /// The price feeds as the contract would store them.
+    pub fn price_feeds(&self) -> Vec<PriceFeed> {
+        self.attestations
+            .iter()
+            .map(PriceAttestation::to_price_feed)
+            .collect()
+    }
+}
+
+impl From<u8> for PriceStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            1 => PriceStatus::Trading,
+            2 => PriceStatus::Halted,
+            3 => PriceStatus::Auction,
+            _ => PriceStatus::Unknown,
+        }
+    }
+}
+
+impl From<PriceStatus> for u8 {
+    fn from(status: PriceStatus) -> Self {
+        match status {
+            PriceStatus::Unknown => 0,
+            PriceStatus::Trading => 1,
+            PriceStatus::Halted => 2,
+            PriceStatus::Auction => 3,
+        }
+    }
+}
+
+impl PriceAttestation {
+    pub fn parse(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = Reader::new(data);
+
+        Ok(Self {
+            product_id: Bits256(reader.array()?),
+            price_id: Bits256(reader.array()?),
+            price: reader.i64()?,
+            conf: reader.u64()?,
+            exponent: reader.i32()?,
+            ema_price: reader.i64()?,
+            ema_conf: reader.u64()?,
+            status: reader.u8()?.into(),
+            num_publishers: reader.u32()?,
+            max_num_publishers: reader.u32()?,
+            attestation_time: reader.i64()?,
+            publish_time: reader.i64()?,
+            prev_publish_time: reader.i64()?,
+            prev_price: reader.i64()?,
+            prev_conf: reader.u64()?,
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(ATTESTATION_SIZE.into());
+        data.extend(self.product_id.0);
+        data.extend(self.price_id.0);
+        data.extend(self.price.to_be_bytes());
+        data.extend(self.conf.to_be_bytes());
+        data.extend(self.exponent.to_be_bytes());
+        data.extend(self.ema_price.to_be_bytes());
+        data.extend(self.ema_conf.to_be_bytes());
+        data.push(self.status.into());
+        data.extend(self.num_publishers.to_be_bytes());
+        data.extend(self.max_num_publishers.to_be_bytes());
+        data.extend(self.attestation_time.to_be_bytes());
+        data.extend(self.publish_time.to_be_bytes());
+        data.extend(self.prev_publish_time.to_be_bytes());
+        data.extend(self.prev_price.to_be_bytes());
+        data.extend(self.prev_conf.to_be_bytes());
+
+        data
+    }
+
+    /// Whether the contract stores the previous price, which it does for every status but trading.
+    pub fn uses_previous_price(&self) -> bool {
+        self.status != PriceStatus::Trading
+    }
+
+    /// The price feed as the contract would store it.
+    pub fn to_price_feed(&self) -> PriceFeed {
+        let (price, conf, publish_time) = if self.uses_previous_price() {
+            (self.prev_price, self.prev_conf, self.prev_publish_time)
+        } else {
+            (self.price, self.conf, self.publish_time)
+        };
+
+        PriceFeed {
+            ema_price: contract_price(self.ema_price, self.ema_conf, self.exponent, publish_time),
+            id: self.price_id,
+            price: contract_price(price, conf, self.exponent, publish_time),
+        }
+    }
+}
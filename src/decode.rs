@@ -0,0 +1,124 @@
+use crate::{constants::TAI64_DIFFERENCE, pyth_utils::Price};
+use thiserror::Error;
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum DecodeError {
+    #[error("unexpected end of data at offset {offset}, {needed} more bytes needed")]
+    UnexpectedEnd { offset: usize, needed: usize },
+    #[error("invalid magic {found:?}, expected {expected:?}")]
+    InvalidMagic { expected: [u8; 4], found: [u8; 4] },
+    #[error("unsupported major version {0}")]
+    InvalidMajorVersion(u16),
+    #[error("unsupported update type {0}")]
+    InvalidUpdateType(u8),
+    #[error("unsupported message type {0}")]
+    InvalidMessageType(u8),
+    #[error("unsupported payload id {0}")]
+    InvalidPayloadId(u8),
+    #[error("attestation size {0} is smaller than an attestation")]
+    InvalidAttestationSize(u16),
+    #[error("governance payload is not for the core module")]
+    InvalidModule,
+    #[error("unsupported Pyth governance module {0}")]
+    InvalidGovernanceModule(u8),
+    #[error("unsupported recipient type {0}, expected 0 for an address or 1 for a contract")]
+    InvalidRecipientType(u8),
+    #[error("unsupported governance action {0}")]
+    InvalidGovernanceAction(u8),
+    #[error("governance instruction sets no data sources")]
+    NoDataSources,
+    #[error("{0} unexpected trailing bytes")]
+    TrailingBytes(usize),
+}
+
+/// Big-endian cursor over encoded update data.
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 0 }
+    }
+
+    pub(crate) fn remaining(&self) -> usize {
+        self.data.len() - self.offset
+    }
+
+    pub(crate) fn bytes(&mut self, len: usize) -> Result<&'a [u8], DecodeError> {
+        if self.remaining() < len {
+            return Err(DecodeError::UnexpectedEnd {
+                offset: self.offset,
+                needed: len - self.remaining(),
+            });
+        }
+
+        let bytes = &self.data[self.offset..self.offset + len];
+        self.offset += len;
+
+        Ok(bytes)
+    }
+
+    pub(crate) fn array<const N: usize>(&mut self) -> Result<[u8; N], DecodeError> {
+        Ok(self.bytes(N)?.try_into().unwrap())
+    }
+
+    pub(crate) fn rest(&mut self) -> &'a [u8] {
+        let rest = &self.data[self.offset..];
+        self.offset = self.data.len();
+
+        rest
+    }
+
+    pub(crate) fn magic(&mut self, expected: [u8; 4]) -> Result<(), DecodeError> {
+        let found = self.array()?;
+        if found != expected {
+            return Err(DecodeError::InvalidMagic { expected, found });
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn u8(&mut self) -> Result<u8, DecodeError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    pub(crate) fn u16(&mut self) -> Result<u16, DecodeError> {
+        Ok(u16::from_be_bytes(self.array()?))
+    }
+
+    pub(crate) fn u32(&mut self) -> Result<u32, DecodeError> {
+        Ok(u32::from_be_bytes(self.array()?))
+    }
+
+    pub(crate) fn u64(&mut self) -> Result<u64, DecodeError> {
+        Ok(u64::from_be_bytes(self.array()?))
+    }
+
+    pub(crate) fn i32(&mut self) -> Result<i32, DecodeError> {
+        Ok(i32::from_be_bytes(self.array()?))
+    }
+
+    pub(crate) fn i64(&mut self) -> Result<i64, DecodeError> {
+        Ok(i64::from_be_bytes(self.array()?))
+    }
+
+    pub(crate) fn finish(self) -> Result<(), DecodeError> {
+        match self.remaining() {
+            0 => Ok(()),
+            remaining => Err(DecodeError::TrailingBytes(remaining)),
+        }
+    }
+}
+
+/// Builds a `Price` the way the contract stores it from Pyth's signed, UNIX timestamped fields.
+pub(crate) fn contract_price(price: i64, conf: u64, expo: i32, publish_time: i64) -> Price {
+    Price {
+        confidence: conf,
+        exponent: expo.unsigned_abs(),
+        // the contract stores the i64 price's bits as a u64
+        price: price as u64,
+        publish_time: (publish_time as u64).wrapping_add(TAI64_DIFFERENCE),
+    }
+}
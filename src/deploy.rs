@@ -0,0 +1,11 @@
+mod config;
+mod dry_run;
+mod error;
+mod manifest;
+mod verify;
+
+pub use config::{DeployConfig, DEFAULT_MANIFEST_PATH};
+pub use dry_run::dry_run_deployment;
+pub use error::DeployError;
+pub use manifest::{ConstructorManifest, DeploymentManifest, DeploymentTransactions};
+pub use verify::{verify_bytecode, verify_deployment, VerificationCheck, VerificationReport};
@@ -0,0 +1,101 @@
+use super::DeployError;
+use crate::network::{
+    decode_vaa, deserialize_optional_bits256, DataSourceConfig, DataSourceSet, Network,
+    NetworkConfig,
+};
+use fuels::types::{Bits256, Bytes};
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+pub const DEFAULT_MANIFEST_PATH: &str = "deployment.json";
+
+/// Configuration of the `deploy_pyth` binary, read from TOML. The deployment parameters come from
+/// a network preset or file, every other field overrides one of them.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct DeployConfig {
+    #[serde(default)]
+    pub network: Network,
+    /// TOML or JSON network config used instead of the `network` preset, relative to the working
+    /// directory.
+    pub network_file: Option<PathBuf>,
+    pub provider_url: Option<String>,
+    pub single_update_fee: Option<u64>,
+    pub valid_time_period_seconds: Option<u64>,
+    pub data_sources: Option<DataSourceSet>,
+    pub governance_data_source: Option<DataSourceConfig>,
+    /// File holding the hex encoded guardian set upgrade VAA passed to the `constructor`, relative
+    /// to the working directory.
+    pub guardian_set_upgrade_vaa: Option<PathBuf>,
+    /// Salt of the contract deployment, random when unset.
+    #[serde(default, deserialize_with = "deserialize_optional_bits256")]
+    pub salt: Option<Bits256>,
+    /// Where the deployment manifest is written.
+    #[serde(default = "default_manifest")]
+    pub manifest: PathBuf,
+}
+
+impl DeployConfig {
+    pub fn from_toml(toml: &str) -> Result<Self, DeployError> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    pub fn from_file(path: &Path) -> Result<Self, DeployError> {
+        Self::from_toml(&read(path)?)
+    }
+
+    /// The network to deploy to, with the overrides of this config applied.
+    pub fn network_config(&self) -> Result<NetworkConfig, DeployError> {
+        let mut network = match &self.network_file {
+            Some(path) => NetworkConfig::from_file(path)?,
+            None => self.network.config(),
+        };
+        if let Some(provider_url) = &self.provider_url {
+            network.provider_url = provider_url.clone();
+        }
+        if let Some(single_update_fee) = self.single_update_fee {
+            network.single_update_fee = single_update_fee;
+        }
+        if let Some(valid_time_period_seconds) = self.valid_time_period_seconds {
+            network.valid_time_period_seconds = valid_time_period_seconds;
+        }
+        if let Some(data_sources) = &self.data_sources {
+            network.data_sources = data_sources.clone();
+        }
+        if let Some(governance_data_source) = self.governance_data_source {
+            network.governance_data_source = governance_data_source;
+        }
+        if let Some(path) = &self.guardian_set_upgrade_vaa {
+            network.guardian_set_upgrade_vaa = Bytes(decode_vaa(&read(path)?)?);
+        }
+
+        Ok(network)
+    }
+}
+
+impl Default for DeployConfig {
+    fn default() -> Self {
+        Self {
+            network: Network::default(),
+            network_file: None,
+            provider_url: None,
+            single_update_fee: None,
+            valid_time_period_seconds: None,
+            data_sources: None,
+            governance_data_source: None,
+            guardian_set_upgrade_vaa: None,
+            salt: None,
+            manifest: default_manifest(),
+        }
+    }
+}
+
+fn read(path: &Path) -> Result<String, DeployError> {
+    std::fs::read_to_string(path).map_err(|source| DeployError::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn default_manifest() -> PathBuf {
+    PathBuf::from(DEFAULT_MANIFEST_PATH)
+}
@@ -0,0 +1,20 @@
+use super::DeployError;
+use crate::pyth_utils::Pyth;
+use fuels::{prelude::Account, types::errors::Error};
+
+/// Dry runs the create transaction `wallet` would send with `salt` against its provider, without
+/// sending anything.
+///
+/// Only the creation of the contract is checked: the `constructor` cannot be dry run before the
+/// contract exists on the network.
+pub async fn dry_run_deployment<A: Account>(wallet: &A, salt: [u8; 32]) -> Result<(), DeployError> {
+    let tx = Pyth::deployment_transaction(wallet, salt).await?;
+    wallet
+        .try_provider()
+        .map_err(Error::from)?
+        .checked_dry_run(tx)
+        .await?
+        .check(None)?;
+
+    Ok(())
+}
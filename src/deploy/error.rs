@@ -0,0 +1,24 @@
+use crate::{decode::DecodeError, network::NetworkError};
+use fuels::types::errors::Error;
+use std::{io, path::PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DeployError {
+    #[error("invalid deploy config")]
+    Config(#[from] toml::de::Error),
+    #[error(transparent)]
+    Network(#[from] NetworkError),
+    #[error("failed to read `{path}`")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("the guardian set upgrade VAA is not hex encoded")]
+    Hex(#[from] hex::FromHexError),
+    #[error("the guardian set upgrade VAA is invalid")]
+    Decode(#[from] DecodeError),
+    #[error(transparent)]
+    Fuels(#[from] Error),
+}
@@ -0,0 +1,96 @@
+use super::DeployError;
+use crate::{
+    network::{
+        decode_vaa, deserialize_bits256, deserialize_optional_bits256, serialize_bits256,
+        DataSourceConfig,
+    },
+    wormhole::Vaa,
+};
+use fuels::types::Bits256;
+use serde::{Deserialize, Serialize};
+
+/// Record of a deployment, written as JSON by the `deploy_pyth` binary.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DeploymentManifest {
+    /// Name of the network config the contract was deployed with.
+    pub network: String,
+    pub provider_url: String,
+    /// Whether the deployment was only simulated, in which case no transaction was sent.
+    pub dry_run: bool,
+    #[serde(
+        deserialize_with = "deserialize_bits256",
+        serialize_with = "serialize_bits256"
+    )]
+    pub contract_id: Bits256,
+    #[serde(
+        deserialize_with = "deserialize_bits256",
+        serialize_with = "serialize_bits256"
+    )]
+    pub deployer: Bits256,
+    #[serde(
+        deserialize_with = "deserialize_bits256",
+        serialize_with = "serialize_bits256"
+    )]
+    pub salt: Bits256,
+    pub transactions: DeploymentTransactions,
+    pub constructor: ConstructorManifest,
+}
+
+/// Ids of the deployment's transactions.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DeploymentTransactions {
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_bits256",
+        serialize_with = "serialize_optional_bits256"
+    )]
+    pub create: Option<Bits256>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_optional_bits256",
+        serialize_with = "serialize_optional_bits256"
+    )]
+    pub constructor: Option<Bits256>,
+}
+
+/// The parameters the `constructor` was called with.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ConstructorManifest {
+    pub data_sources: Vec<DataSourceConfig>,
+    pub governance_data_source: DataSourceConfig,
+    pub single_update_fee: u64,
+    pub valid_time_period_seconds: u64,
+    /// Hex encoded guardian set upgrade VAA.
+    pub wormhole_guardian_set_upgrade: String,
+    /// Index of the guardian set the VAA installs.
+    pub guardian_set_index: u32,
+}
+
+impl DeploymentManifest {
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl ConstructorManifest {
+    /// The guardian set upgrade VAA the `constructor` was called with.
+    pub fn wormhole_guardian_set_upgrade(&self) -> Result<Vaa, DeployError> {
+        let vaa = decode_vaa(&self.wormhole_guardian_set_upgrade)?;
+
+        Ok(Vaa::parse(&vaa)?)
+    }
+}
+
+fn serialize_optional_bits256<S: serde::Serializer>(
+    bits: &Option<Bits256>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match bits {
+        Some(bits) => serialize_bits256(bits, serializer),
+        None => serializer.serialize_none(),
+    }
+}
@@ -0,0 +1,269 @@
+use super::{DeployError, DeploymentManifest};
+use crate::{
+    network::DataSourceConfig,
+    pyth_utils::{GuardianSet, Pyth, State, WormholeProvider},
+    wormhole::GuardianSetUpgrade,
+};
+use fuels::{
+    client::FuelClient,
+    prelude::{Account, Contract, Provider},
+    tx::{Bytes32, Salt},
+    types::{errors::Error, Address, Bits256, ContractId, Identity},
+};
+use std::fmt;
+
+/// A value of the deployed contract compared against the value the manifest expects.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerificationCheck {
+    pub name: String,
+    pub expected: String,
+    /// The deployed value, or the error reading it.
+    pub actual: String,
+}
+
+/// Outcome of verifying a deployment against its manifest.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VerificationReport {
+    pub checks: Vec<VerificationCheck>,
+}
+
+impl VerificationCheck {
+    fn new(name: impl Into<String>, expected: String, actual: Result<String, Error>) -> Self {
+        Self {
+            name: name.into(),
+            expected,
+            actual: actual.unwrap_or_else(|error| format!("error: {error}")),
+        }
+    }
+
+    pub fn passed(&self) -> bool {
+        self.expected == self.actual
+    }
+}
+
+impl VerificationReport {
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(VerificationCheck::passed)
+    }
+
+    pub fn failures(&self) -> impl Iterator<Item = &VerificationCheck> {
+        self.checks.iter().filter(|check| !check.passed())
+    }
+
+    fn check(&mut self, name: impl Into<String>, expected: String, actual: Result<String, Error>) {
+        self.checks
+            .push(VerificationCheck::new(name, expected, actual));
+    }
+}
+
+impl fmt::Display for VerificationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            if check.passed() {
+                writeln!(f, "PASS {}", check.name)?;
+            } else {
+                writeln!(f, "FAIL {}", check.name)?;
+                writeln!(f, "  - {}", check.expected)?;
+                writeln!(f, "  + {}", check.actual)?;
+            }
+        }
+
+        let failures = self.failures().count();
+        if failures == 0 {
+            write!(f, "{} checks passed", self.checks.len())
+        } else {
+            write!(f, "{failures} of {} checks failed", self.checks.len())
+        }
+    }
+}
+
+/// Compares the contract `pyth` is connected to against `manifest`: its id, which the manifest's
+/// deployer and salt derive from the local binary, its bytecode read from the chain, its constructor
+/// parameters, the guardian set and Wormhole provider of the guardian set upgrade VAA, and the
+/// revoked ownership.
+///
+/// Failing reads are reported as failed checks, only an undecodable manifest VAA or local binary
+/// is an error.
+pub async fn verify_deployment<A: Account>(
+    pyth: &Pyth<A>,
+    manifest: &DeploymentManifest,
+) -> Result<VerificationReport, DeployError> {
+    let constructor = &manifest.constructor;
+    let vaa = constructor.wormhole_guardian_set_upgrade()?;
+    let upgrade = GuardianSetUpgrade::parse(&vaa.payload)?;
+    let deployer = Address::from(manifest.deployer.0);
+    let contract_id = ContractId::from(pyth.instance.contract_id());
+    let mut report = VerificationReport::default();
+
+    let local_contract_id = Pyth::deployment_contract_id(deployer, manifest.salt.0)?;
+    report.check(
+        "contract_id",
+        format_contract_id(&local_contract_id),
+        Ok(format_contract_id(&contract_id)),
+    );
+    report.checks.push(
+        verify_bytecode(
+            pyth.wallet.try_provider().map_err(Error::from)?,
+            contract_id,
+            deployer,
+        )
+        .await?,
+    );
+    report.check(
+        "valid_data_sources",
+        format_data_sources(&constructor.data_sources),
+        pyth.valid_data_sources().await.map(|response| {
+            let data_sources: Vec<DataSourceConfig> =
+                response.value.iter().map(DataSourceConfig::from).collect();
+            format_data_sources(&data_sources)
+        }),
+    );
+    report.check(
+        "governance_data_source",
+        format_data_sources(&[constructor.governance_data_source]),
+        pyth.governance_data_source()
+            .await
+            .map(|response| format_data_sources(&[DataSourceConfig::from(&response.value)])),
+    );
+    report.check(
+        "single_update_fee",
+        constructor.single_update_fee.to_string(),
+        pyth.single_update_fee()
+            .await
+            .map(|response| response.value.to_string()),
+    );
+    report.check(
+        "valid_time_period",
+        constructor.valid_time_period_seconds.to_string(),
+        pyth.valid_time_period()
+            .await
+            .map(|response| response.value.to_string()),
+    );
+    report.check(
+        "current_guardian_set_index",
+        constructor.guardian_set_index.to_string(),
+        pyth.current_guardian_set_index()
+            .await
+            .map(|response| response.value.to_string()),
+    );
+    report.check(
+        format!("guardian_set({})", constructor.guardian_set_index),
+        format_guardian_set(&upgrade.guardian_set()),
+        pyth.guardian_set(constructor.guardian_set_index)
+            .await
+            .map(|response| format_guardian_set(&response.value)),
+    );
+    report.check(
+        "current_wormhole_provider",
+        format_wormhole_provider(&WormholeProvider {
+            governance_chain_id: vaa.emitter_chain_id,
+            governance_contract: vaa.emitter_address,
+        }),
+        pyth.current_wormhole_provider()
+            .await
+            .map(|response| format_wormhole_provider(&response.value)),
+    );
+    // the constructor renounces the ownership it initializes
+    report.check(
+        "owner",
+        format_state(&State::Revoked),
+        pyth.owner()
+            .await
+            .map(|response| format_state(&response.value)),
+    );
+
+    Ok(report)
+}
+
+/// Compares the root of the bytecode deployed at `contract_id` with the root of the local binary
+/// `deployer` deploys.
+pub async fn verify_bytecode(
+    provider: &Provider,
+    contract_id: ContractId,
+    deployer: Address,
+) -> Result<VerificationCheck, DeployError> {
+    let local_code_root = Pyth::deployment_code_root(deployer)?;
+
+    Ok(VerificationCheck::new(
+        "bytecode",
+        format_bytes32(&local_code_root),
+        deployed_code_root(provider, contract_id).await,
+    ))
+}
+
+async fn deployed_code_root(provider: &Provider, contract_id: ContractId) -> Result<String, Error> {
+    let client =
+        FuelClient::new(provider.url()).map_err(|error| Error::ProviderError(error.to_string()))?;
+    let contract = client
+        .contract(&contract_id)
+        .await
+        .map_err(|error| Error::ProviderError(error.to_string()))?;
+
+    Ok(match contract {
+        Some(contract) => {
+            format_bytes32(&Contract::new(contract.bytecode, Salt::zeroed(), vec![]).code_root())
+        }
+        None => "no contract deployed".to_string(),
+    })
+}
+
+fn format_bits256(bits: &Bits256) -> String {
+    format!("0x{}", hex::encode(bits.0))
+}
+
+fn format_bytes32(bytes: &Bytes32) -> String {
+    format!("0x{}", hex::encode(bytes))
+}
+
+fn format_contract_id(contract_id: &ContractId) -> String {
+    format!("0x{}", hex::encode(contract_id))
+}
+
+fn format_data_sources(data_sources: &[DataSourceConfig]) -> String {
+    let data_sources: Vec<String> = data_sources
+        .iter()
+        .map(|data_source| {
+            format!(
+                "{}:{}",
+                data_source.chain_id,
+                format_bits256(&data_source.emitter_address)
+            )
+        })
+        .collect();
+
+    format!("[{}]", data_sources.join(", "))
+}
+
+fn format_guardian_set(guardian_set: &GuardianSet) -> String {
+    let keys: Vec<String> = guardian_set.keys.iter().map(format_bits256).collect();
+
+    format!(
+        "expiration_time: {}, keys: [{}]",
+        guardian_set.expiration_time,
+        keys.join(", ")
+    )
+}
+
+fn format_wormhole_provider(provider: &WormholeProvider) -> String {
+    format!(
+        "{}:{}",
+        provider.governance_chain_id,
+        format_bits256(&provider.governance_contract)
+    )
+}
+
+fn format_state(state: &State) -> String {
+    match state {
+        State::Uninitialized => "Uninitialized".to_string(),
+        State::Initialized(Identity::Address(address)) => {
+            format!("Initialized(Address(0x{address}))")
+        }
+        State::Initialized(Identity::ContractId(contract_id)) => {
+            format!(
+                "Initialized(ContractId({}))",
+                format_contract_id(contract_id)
+            )
+        }
+        State::Revoked => "Revoked".to_string(),
+    }
+}
@@ -0,0 +1,146 @@
+use crate::pyth_utils::{
+    ConstructedEvent, FeeRefundedEvent, FeesWithdrawnEvent, NewGuardianSetEvent,
+    OwnershipRenounced, OwnershipSet, Price, PriceFeedUpdateEvent, UpdatedPriceFeedsEvent,
+};
+use fuels::{
+    core::{
+        codec::LogDecoder,
+        traits::{Parameterize, Tokenizable},
+    },
+    programs::call_response::FuelCallResponse,
+    tx::Receipt,
+    types::errors::Error,
+};
+
+/// An event logged by the Pyth contract.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PythEvent {
+    /// Logged by the `constructor`, after the ownership events.
+    Constructed(ConstructedEvent),
+    NewGuardianSet(NewGuardianSetEvent),
+    /// Logged by `update_price_feeds`, and by `update_price_feeds_if_necessary` when it updates,
+    /// with the ids of the price feeds newer than their stored price, in update data order.
+    UpdatedPriceFeeds(UpdatedPriceFeedsEvent),
+    /// Logged for every price feed an update stores, before the [PythEvent::UpdatedPriceFeeds] of
+    /// the update.
+    PriceFeedUpdate(PriceFeedUpdateEvent),
+    /// Logged by `update_price_feeds` when the payment exceeds the update fee, and by
+    /// `update_price_feeds_if_necessary` when no price feed is stale, last in the call.
+    FeeRefunded(FeeRefundedEvent),
+    /// Logged by `execute_governance_instruction` when it executes a `WithdrawFees` instruction.
+    FeesWithdrawn(FeesWithdrawnEvent),
+    OwnershipSet(OwnershipSet),
+    OwnershipRenounced(OwnershipRenounced),
+}
+
+/// Response of a call along with the event it logged.
+#[derive(Debug)]
+pub struct EventCallResponse<E> {
+    pub response: FuelCallResponse<()>,
+    pub event: E,
+}
+
+/// Decodes every event of the contract of `log_decoder` in `receipts`, in the order they were
+/// logged. Logs of other contracts and logs that are not events, such as errors, are skipped.
+pub fn decode_logs(
+    log_decoder: &LogDecoder,
+    receipts: &[Receipt],
+) -> Result<Vec<PythEvent>, Error> {
+    let mut events = vec![];
+    for receipt in receipts {
+        let receipt = std::slice::from_ref(receipt);
+        events.extend(
+            log_decoder
+                .decode_logs_with_type::<ConstructedEvent>(receipt)?
+                .into_iter()
+                .map(PythEvent::Constructed),
+        );
+        events.extend(
+            log_decoder
+                .decode_logs_with_type::<NewGuardianSetEvent>(receipt)?
+                .into_iter()
+                .map(PythEvent::NewGuardianSet),
+        );
+        events.extend(
+            log_decoder
+                .decode_logs_with_type::<UpdatedPriceFeedsEvent>(receipt)?
+                .into_iter()
+                .map(PythEvent::UpdatedPriceFeeds),
+        );
+        events.extend(
+            log_decoder
+                .decode_logs_with_type::<PriceFeedUpdateEvent>(receipt)?
+                .into_iter()
+                .map(PythEvent::PriceFeedUpdate),
+        );
+        events.extend(
+            log_decoder
+                .decode_logs_with_type::<FeeRefundedEvent>(receipt)?
+                .into_iter()
+                .map(PythEvent::FeeRefunded),
+        );
+        events.extend(
+            log_decoder
+                .decode_logs_with_type::<FeesWithdrawnEvent>(receipt)?
+                .into_iter()
+                .map(PythEvent::FeesWithdrawn),
+        );
+        events.extend(
+            log_decoder
+                .decode_logs_with_type::<OwnershipSet>(receipt)?
+                .into_iter()
+                .map(PythEvent::OwnershipSet),
+        );
+        events.extend(
+            log_decoder
+                .decode_logs_with_type::<OwnershipRenounced>(receipt)?
+                .into_iter()
+                .map(PythEvent::OwnershipRenounced),
+        );
+    }
+
+    Ok(events)
+}
+
+/// The amount of the payment refunded by the call of `response`, 0 if the contract kept all of it.
+pub fn refunded_fee(response: &FuelCallResponse<()>) -> Result<u64, Error> {
+    Ok(response
+        .decode_logs_with_type::<FeeRefundedEvent>()?
+        .iter()
+        .map(|event| event.amount)
+        .sum())
+}
+
+impl PriceFeedUpdateEvent {
+    /// The price stored by the update.
+    pub fn price(&self) -> Price {
+        Price {
+            confidence: self.confidence,
+            exponent: self.exponent,
+            price: self.price,
+            publish_time: self.publish_time,
+        }
+    }
+}
+
+impl<E> EventCallResponse<E>
+where
+    E: Tokenizable + Parameterize + 'static,
+{
+    /// Takes the last event of type `E` logged by the call of `response`.
+    pub(crate) fn from_response(response: FuelCallResponse<()>) -> Result<Self, Error> {
+        let event = response
+            .decode_logs_with_type::<E>()?
+            .pop()
+            .ok_or_else(|| {
+                Error::InvalidData(format!("missing `{}` log", std::any::type_name::<E>()))
+            })?;
+
+        Ok(Self { response, event })
+    }
+
+    /// See [refunded_fee].
+    pub fn refunded_fee(&self) -> Result<u64, Error> {
+        refunded_fee(&self.response)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{
+    accumulator::{AccumulatorUpdate, ACCUMULATOR_MAGIC, MAJOR_VERSION},
+    decode::{DecodeError, Reader},
+};
+use fuels::types::Bytes;
+use thiserror::Error;
+
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum FeeError {
+    #[error(transparent)]
+    Decode(#[from] DecodeError),
+    #[error("fee of {number_of_updates} updates at {single_update_fee} each overflows a u64")]
+    Overflow {
+        number_of_updates: u64,
+        single_update_fee: u64,
+    },
+}
+
+/// Computes the fee `update_fee` returns for `update_data`, without calling the contract.
+///
+/// Like the contract, an accumulator update counts its number of updates, which is read without
+/// verifying the update, and any other update data counts as a single batch attestation update.
+pub fn compute_update_fee(update_data: &[Bytes], single_update_fee: u64) -> Result<u64, FeeError> {
+    let total_number_of_updates = update_data
+        .iter()
+        .map(|data| number_of_updates(&data.0))
+        .sum::<Result<u64, DecodeError>>()?;
+
+    total_number_of_updates
+        .checked_mul(single_update_fee)
+        .ok_or(FeeError::Overflow {
+            number_of_updates: total_number_of_updates,
+            single_update_fee,
+        })
+}
+
+/// The number of updates the contract charges for `data`.
+pub fn number_of_updates(data: &[u8]) -> Result<u64, DecodeError> {
+    if !AccumulatorUpdate::is_accumulator_update(data) {
+        return Ok(1);
+    }
+
+    let mut reader = Reader::new(data);
+    reader.magic(ACCUMULATOR_MAGIC)?;
+    let major_version = reader.u8()?;
+    if major_version != MAJOR_VERSION {
+        return Err(DecodeError::InvalidMajorVersion(major_version.into()));
+    }
+    let _minor_version = reader.u8()?;
+    let trailing_header_size = reader.u8()?;
+    // the update type is not checked by `update_fee`
+    reader.bytes(usize::from(trailing_header_size) + 1)?;
+    let vaa_size = reader.u16()?;
+    reader.bytes(vaa_size.into())?;
+
+    Ok(reader.u8()?.into())
+}
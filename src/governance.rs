@@ -0,0 +1,163 @@
+use crate::{
+    decode::{DecodeError, Reader},
+    pyth_utils::DataSource,
+};
+use fuels::types::{Address, Bits256, ContractId, Identity};
+
+/// "PTGM", the magic of Pyth governance messages.
+pub const GOVERNANCE_MAGIC: [u8; 4] = *b"PTGM";
+/// The module of governance instructions targeting Pyth receiver contracts.
+pub const TARGET_CHAIN_MODULE: u8 = 1;
+/// The target chain id of instructions for every chain, the only one the contract accepts as Fuel
+/// has no Wormhole chain id.
+pub const ALL_CHAINS: u16 = 0;
+
+const SET_DATA_SOURCES_ACTION: u8 = 2;
+const SET_FEE_ACTION: u8 = 3;
+const SET_VALID_PERIOD_ACTION: u8 = 4;
+/// A Fuel-specific extension rather than one of Pyth's target chain actions: its payload, the
+/// amount (u64), recipient type (u8) and recipient (b256) of 41 bytes, is not produced by Pyth's
+/// governance tooling and is encoded by [GovernanceInstruction::encode].
+const WITHDRAW_FEES_ACTION: u8 = 9;
+
+/// An action of the target chain module supported by `execute_governance_instruction`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GovernanceAction {
+    /// Replaces the valid data sources.
+    SetDataSources(Vec<DataSource>),
+    /// Sets the single update fee to `value * 10^expo`.
+    SetFee {
+        value: u64,
+        expo: u64,
+    },
+    SetValidPeriod {
+        valid_time_period_seconds: u64,
+    },
+    /// Transfers `amount` of the base asset fees held by the contract to `recipient`.
+    WithdrawFees {
+        amount: u64,
+        recipient: Identity,
+    },
+}
+
+/// The payload of a Pyth governance VAA.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GovernanceInstruction {
+    pub target_chain_id: u16,
+    pub action: GovernanceAction,
+}
+
+impl GovernanceAction {
+    fn id(&self) -> u8 {
+        match self {
+            GovernanceAction::SetDataSources(_) => SET_DATA_SOURCES_ACTION,
+            GovernanceAction::SetFee { .. } => SET_FEE_ACTION,
+            GovernanceAction::SetValidPeriod { .. } => SET_VALID_PERIOD_ACTION,
+            GovernanceAction::WithdrawFees { .. } => WITHDRAW_FEES_ACTION,
+        }
+    }
+}
+
+impl GovernanceInstruction {
+    /// An instruction of `action` for every chain.
+    pub fn new(action: GovernanceAction) -> Self {
+        Self {
+            target_chain_id: ALL_CHAINS,
+            action,
+        }
+    }
+
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = Reader::new(payload);
+
+        reader.magic(GOVERNANCE_MAGIC)?;
+        let module = reader.u8()?;
+        if module != TARGET_CHAIN_MODULE {
+            return Err(DecodeError::InvalidGovernanceModule(module));
+        }
+        let action = reader.u8()?;
+        let target_chain_id = reader.u16()?;
+        let action = match action {
+            SET_DATA_SOURCES_ACTION => {
+                let number_of_data_sources = reader.u8()?;
+                // the contract reverts with `InvalidDataSourcesLength` rather than accept no source
+                if number_of_data_sources == 0 {
+                    return Err(DecodeError::NoDataSources);
+                }
+                GovernanceAction::SetDataSources(
+                    (0..number_of_data_sources)
+                        .map(|_| {
+                            Ok(DataSource {
+                                chain_id: reader.u16()?,
+                                emitter_address: Bits256(reader.array()?),
+                            })
+                        })
+                        .collect::<Result<Vec<DataSource>, DecodeError>>()?,
+                )
+            }
+            SET_FEE_ACTION => GovernanceAction::SetFee {
+                value: reader.u64()?,
+                expo: reader.u64()?,
+            },
+            SET_VALID_PERIOD_ACTION => GovernanceAction::SetValidPeriod {
+                valid_time_period_seconds: reader.u64()?,
+            },
+            WITHDRAW_FEES_ACTION => {
+                let amount = reader.u64()?;
+                let recipient = match reader.u8()? {
+                    0 => Identity::Address(Address::new(reader.array()?)),
+                    1 => Identity::ContractId(ContractId::new(reader.array()?)),
+                    recipient_type => {
+                        return Err(DecodeError::InvalidRecipientType(recipient_type))
+                    }
+                };
+                GovernanceAction::WithdrawFees { amount, recipient }
+            }
+            action => return Err(DecodeError::InvalidGovernanceAction(action)),
+        };
+        reader.finish()?;
+
+        Ok(Self {
+            target_chain_id,
+            action,
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut payload = GOVERNANCE_MAGIC.to_vec();
+        payload.push(TARGET_CHAIN_MODULE);
+        payload.push(self.action.id());
+        payload.extend(self.target_chain_id.to_be_bytes());
+        match &self.action {
+            GovernanceAction::SetDataSources(data_sources) => {
+                payload.push(data_sources.len() as u8);
+                for data_source in data_sources {
+                    payload.extend(data_source.chain_id.to_be_bytes());
+                    payload.extend(data_source.emitter_address.0);
+                }
+            }
+            GovernanceAction::SetFee { value, expo } => {
+                payload.extend(value.to_be_bytes());
+                payload.extend(expo.to_be_bytes());
+            }
+            GovernanceAction::SetValidPeriod {
+                valid_time_period_seconds,
+            } => payload.extend(valid_time_period_seconds.to_be_bytes()),
+            GovernanceAction::WithdrawFees { amount, recipient } => {
+                payload.extend(amount.to_be_bytes());
+                match recipient {
+                    Identity::Address(address) => {
+                        payload.push(0);
+                        payload.extend(**address);
+                    }
+                    Identity::ContractId(contract_id) => {
+                        payload.push(1);
+                        payload.extend(**contract_id);
+                    }
+                }
+            }
+        }
+
+        payload
+    }
+}
@@ -0,0 +1,200 @@
+mod error;
+mod stream;
+mod types;
+
+pub use error::HermesError;
+pub use stream::{StreamedPriceUpdate, SubscriptionConfig};
+pub use types::{
+    BinaryPriceUpdate, EncodingType, ParsedPriceUpdate, PriceUpdate, RpcPrice, RpcPriceFeedMetadata,
+};
+
+use fuels::types::{Bits256, Bytes};
+use reqwest::{RequestBuilder, Url};
+use std::time::Duration;
+
+pub const DEFAULT_HERMES_URL: &str = "https://hermes.pyth.network";
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+pub const DEFAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Clone, Debug)]
+pub struct HermesConfig {
+    pub base_url: String,
+    pub connect_timeout: Duration,
+    pub request_timeout: Duration,
+}
+
+impl Default for HermesConfig {
+    fn default() -> Self {
+        Self {
+            base_url: DEFAULT_HERMES_URL.to_string(),
+            connect_timeout: DEFAULT_CONNECT_TIMEOUT,
+            request_timeout: DEFAULT_REQUEST_TIMEOUT,
+        }
+    }
+}
+
+impl HermesConfig {
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    pub fn with_connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    pub fn with_request_timeout(mut self, request_timeout: Duration) -> Self {
+        self.request_timeout = request_timeout;
+        self
+    }
+}
+
+/// Client for Pyth's Hermes price service.
+#[derive(Clone, Debug)]
+pub struct HermesClient {
+    base_url: Url,
+    config: HermesConfig,
+    http: reqwest::Client,
+    // streaming responses are long-lived, so they are not subject to the request timeout
+    stream_http: reqwest::Client,
+}
+
+impl HermesClient {
+    pub fn new(config: HermesConfig) -> Result<Self, HermesError> {
+        // a trailing slash makes `Url::join` append to, rather than replace, the last path segment
+        let base_url = format!("{}/", config.base_url.trim_end_matches('/'));
+        let base_url =
+            Url::parse(&base_url).map_err(|_| HermesError::InvalidUrl(config.base_url.clone()))?;
+
+        let http = reqwest::Client::builder()
+            .connect_timeout(config.connect_timeout)
+            .timeout(config.request_timeout)
+            .build()?;
+        let stream_http = reqwest::Client::builder()
+            .connect_timeout(config.connect_timeout)
+            .build()?;
+
+        Ok(Self {
+            base_url,
+            config,
+            http,
+            stream_http,
+        })
+    }
+
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    pub fn config(&self) -> &HermesConfig {
+        &self.config
+    }
+
+    /// Fetches the latest price update for `price_feed_ids` from `/v2/updates/price/latest`.
+    pub async fn latest_price_updates(
+        &self,
+        price_feed_ids: &[Bits256],
+        encoding: EncodingType,
+        parsed: bool,
+    ) -> Result<PriceUpdate, HermesError> {
+        let request =
+            self.price_update_request("v2/updates/price/latest", price_feed_ids, encoding, parsed)?;
+
+        self.send(request).await
+    }
+
+    /// Fetches the latest update data for `price_feed_ids`, ready to be passed to `update_price_feeds`.
+    pub async fn latest_update_data(
+        &self,
+        price_feed_ids: &[Bits256],
+    ) -> Result<Vec<Bytes>, HermesError> {
+        self.latest_price_updates(price_feed_ids, EncodingType::Hex, false)
+            .await?
+            .update_data()
+    }
+
+    /// Fetches the first price update published at or after `publish_time` (UNIX seconds) for
+    /// `price_feed_ids` from `/v2/updates/price/{publish_time}`.
+    pub async fn price_updates_at(
+        &self,
+        publish_time: u64,
+        price_feed_ids: &[Bits256],
+        encoding: EncodingType,
+        parsed: bool,
+    ) -> Result<PriceUpdate, HermesError> {
+        let request = self.price_update_request(
+            &format!("v2/updates/price/{publish_time}"),
+            price_feed_ids,
+            encoding,
+            parsed,
+        )?;
+
+        self.send(request).await
+    }
+
+    /// Fetches the update data published at or after `publish_time` (UNIX seconds) for
+    /// `price_feed_ids`, ready to be passed to `parse_price_feed_updates`.
+    pub async fn update_data_at(
+        &self,
+        publish_time: u64,
+        price_feed_ids: &[Bits256],
+    ) -> Result<Vec<Bytes>, HermesError> {
+        self.price_updates_at(publish_time, price_feed_ids, EncodingType::Hex, false)
+            .await?
+            .update_data()
+    }
+
+    fn url(&self, path: &str) -> Result<Url, HermesError> {
+        self.base_url
+            .join(path)
+            .map_err(|_| HermesError::InvalidUrl(format!("{}{}", self.base_url, path)))
+    }
+
+    fn price_update_request(
+        &self,
+        path: &str,
+        price_feed_ids: &[Bits256],
+        encoding: EncodingType,
+        parsed: bool,
+    ) -> Result<RequestBuilder, HermesError> {
+        Ok(self.http.get(self.url(path)?).query(&price_update_query(
+            price_feed_ids,
+            encoding,
+            parsed,
+        )))
+    }
+
+    async fn send<T: serde::de::DeserializeOwned>(
+        &self,
+        request: RequestBuilder,
+    ) -> Result<T, HermesError> {
+        let response = request.send().await?;
+        let status = response.status();
+        let body = response.text().await?;
+
+        if !status.is_success() {
+            return Err(HermesError::Status {
+                status: status.as_u16(),
+                body,
+            });
+        }
+
+        Ok(serde_json::from_str(&body)?)
+    }
+}
+
+fn price_update_query(
+    price_feed_ids: &[Bits256],
+    encoding: EncodingType,
+    parsed: bool,
+) -> Vec<(&'static str, String)> {
+    let mut query: Vec<(&str, String)> = price_feed_ids
+        .iter()
+        .map(|id| ("ids[]", hex::encode(id.0)))
+        .collect();
+    query.push(("encoding", encoding.as_str().to_string()));
+    query.push(("parsed", parsed.to_string()));
+
+    query
+}
@@ -0,0 +1,36 @@
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum HermesError {
+    #[error("invalid Hermes url `{0}`")]
+    InvalidUrl(String),
+    #[error("invalid price feed id `{0}`")]
+    InvalidPriceFeedId(String),
+    #[error("Hermes request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("Hermes responded with status {status}: {body}")]
+    Status { status: u16, body: String },
+    #[error("could not deserialize Hermes response: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("could not decode base64 update data: {0}")]
+    Base64(#[from] base64::DecodeError),
+    #[error("could not decode hex update data: {0}")]
+    Hex(#[from] hex::FromHexError),
+    #[error("Hermes response is missing the `parsed` section")]
+    MissingParsedSection,
+    /// `source` is the error of the last failed attempt, if it failed with one rather than with the
+    /// stream closing or going idle.
+    #[error("gave up on the Hermes stream after {attempts} failed reconnect attempts")]
+    ReconnectAttemptsExhausted {
+        attempts: u32,
+        #[source]
+        source: Option<Box<HermesError>>,
+    },
+}
+
+impl HermesError {
+    /// Whether the request was aborted by the client's connect or request timeout.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, HermesError::Request(error) if error.is_timeout())
+    }
+}
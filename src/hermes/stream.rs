@@ -0,0 +1,282 @@
+use crate::{
+    hermes::{price_update_query, EncodingType, HermesClient, HermesError, PriceUpdate},
+    pyth_utils::PriceFeed,
+};
+use fuels::types::{Bits256, Bytes};
+use futures::{
+    stream::{self, BoxStream},
+    Stream, StreamExt,
+};
+use reqwest::header::ACCEPT;
+use std::{collections::HashMap, time::Duration};
+
+#[derive(Clone, Debug)]
+pub struct SubscriptionConfig {
+    pub encoding: EncodingType,
+    /// Reconnect when no data has been received for this long.
+    pub idle_timeout: Duration,
+    /// Delay before the first reconnect, doubled on each consecutive failure.
+    pub reconnect_delay: Duration,
+    pub max_reconnect_delay: Duration,
+    /// Consecutive failed (re)connects after which the subscription ends with an error.
+    pub max_reconnect_attempts: u32,
+}
+
+impl Default for SubscriptionConfig {
+    fn default() -> Self {
+        Self {
+            encoding: EncodingType::Hex,
+            idle_timeout: Duration::from_secs(30),
+            reconnect_delay: Duration::from_millis(500),
+            max_reconnect_delay: Duration::from_secs(30),
+            max_reconnect_attempts: 10,
+        }
+    }
+}
+
+impl SubscriptionConfig {
+    pub fn with_encoding(mut self, encoding: EncodingType) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    pub fn with_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    pub fn with_reconnect_delay(mut self, reconnect_delay: Duration) -> Self {
+        self.reconnect_delay = reconnect_delay;
+        self
+    }
+
+    pub fn with_max_reconnect_delay(mut self, max_reconnect_delay: Duration) -> Self {
+        self.max_reconnect_delay = max_reconnect_delay;
+        self
+    }
+
+    pub fn with_max_reconnect_attempts(mut self, max_reconnect_attempts: u32) -> Self {
+        self.max_reconnect_attempts = max_reconnect_attempts;
+        self
+    }
+}
+
+/// A price update received from the Hermes stream.
+#[derive(Clone, Debug, PartialEq)]
+pub struct StreamedPriceUpdate {
+    /// Update data, ready to be passed to `update_price_feeds`.
+    pub update_data: Vec<Bytes>,
+    pub price_feeds: Vec<PriceFeed>,
+}
+
+impl HermesClient {
+    /// Subscribes to `/v2/updates/price/stream` for `price_feed_ids` using the default [SubscriptionConfig].
+    pub fn subscribe(
+        &self,
+        price_feed_ids: &[Bits256],
+    ) -> impl Stream<Item = Result<StreamedPriceUpdate, HermesError>> + Send + 'static {
+        self.subscribe_with_config(price_feed_ids, SubscriptionConfig::default())
+    }
+
+    /// Subscribes to `/v2/updates/price/stream` for `price_feed_ids`.
+    ///
+    /// The subscription reconnects when the connection drops or goes idle, and resumes by skipping
+    /// updates that carry no price newer than the ones already yielded. It ends with
+    /// [HermesError::ReconnectAttemptsExhausted] once `max_reconnect_attempts` consecutive
+    /// reconnects have failed.
+    pub fn subscribe_with_config(
+        &self,
+        price_feed_ids: &[Bits256],
+        config: SubscriptionConfig,
+    ) -> impl Stream<Item = Result<StreamedPriceUpdate, HermesError>> + Send + 'static {
+        let subscription = Subscription {
+            client: self.clone(),
+            price_feed_ids: price_feed_ids.to_vec(),
+            config,
+            body: None,
+            buffer: vec![],
+            failed_attempts: 0,
+            last_error: None,
+            latest_publish_times: HashMap::new(),
+            terminated: false,
+        };
+
+        stream::unfold(subscription, |mut subscription| async move {
+            let item = subscription.next_update().await?;
+            Some((item, subscription))
+        })
+    }
+
+    async fn open_price_stream(
+        &self,
+        price_feed_ids: &[Bits256],
+        encoding: EncodingType,
+    ) -> Result<BoxStream<'static, reqwest::Result<Vec<u8>>>, HermesError> {
+        let response = self
+            .stream_http
+            .get(self.url("v2/updates/price/stream")?)
+            .header(ACCEPT, "text/event-stream")
+            .query(&price_update_query(price_feed_ids, encoding, true))
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(HermesError::Status {
+                status: status.as_u16(),
+                body: response.text().await?,
+            });
+        }
+
+        Ok(response
+            .bytes_stream()
+            .map(|chunk| chunk.map(|chunk| chunk.to_vec()))
+            .boxed())
+    }
+}
+
+struct Subscription {
+    client: HermesClient,
+    price_feed_ids: Vec<Bits256>,
+    config: SubscriptionConfig,
+    body: Option<BoxStream<'static, reqwest::Result<Vec<u8>>>>,
+    buffer: Vec<u8>,
+    failed_attempts: u32,
+    /// Error of the last failed attempt, cleared once data is received.
+    last_error: Option<HermesError>,
+    latest_publish_times: HashMap<[u8; 32], u64>,
+    terminated: bool,
+}
+
+impl Subscription {
+    async fn next_update(&mut self) -> Option<Result<StreamedPriceUpdate, HermesError>> {
+        if self.terminated {
+            return None;
+        }
+
+        loop {
+            while let Some(data) = self.next_event_data() {
+                self.failed_attempts = 0;
+                self.last_error = None;
+
+                match self.parse_update(&data) {
+                    Ok(Some(update)) => return Some(Ok(update)),
+                    Ok(None) => continue,
+                    Err(error) => return Some(Err(error)),
+                }
+            }
+
+            let Some(body) = self.body.as_mut() else {
+                if let Err(error) = self.connect().await {
+                    self.terminated = true;
+                    return Some(Err(error));
+                }
+                continue;
+            };
+
+            match tokio::time::timeout(self.config.idle_timeout, body.next()).await {
+                Ok(Some(Ok(chunk))) => self
+                    .buffer
+                    .extend(chunk.into_iter().filter(|byte| *byte != b'\r')),
+                // the stream was closed, failed or went idle
+                result => {
+                    if let Ok(Some(Err(error))) = result {
+                        self.last_error = Some(error.into());
+                    }
+                    self.body = None;
+                    self.buffer.clear();
+                    self.failed_attempts += 1;
+                }
+            }
+        }
+    }
+
+    async fn connect(&mut self) -> Result<(), HermesError> {
+        loop {
+            if self.failed_attempts > self.config.max_reconnect_attempts {
+                return Err(HermesError::ReconnectAttemptsExhausted {
+                    attempts: self.config.max_reconnect_attempts,
+                    source: self.last_error.take().map(Box::new),
+                });
+            }
+
+            if self.failed_attempts > 0 {
+                tokio::time::sleep(self.reconnect_delay()).await;
+            }
+
+            match self
+                .client
+                .open_price_stream(&self.price_feed_ids, self.config.encoding)
+                .await
+            {
+                Ok(body) => {
+                    self.body = Some(body);
+                    return Ok(());
+                }
+                Err(error) => {
+                    self.last_error = Some(error);
+                    self.failed_attempts += 1;
+                }
+            }
+        }
+    }
+
+    fn reconnect_delay(&self) -> Duration {
+        let factor = 2u32.saturating_pow(self.failed_attempts.saturating_sub(1));
+
+        self.config
+            .reconnect_delay
+            .saturating_mul(factor)
+            .min(self.config.max_reconnect_delay)
+    }
+
+    /// Pops the next complete server-sent event from the buffer and returns its `data` field.
+    fn next_event_data(&mut self) -> Option<String> {
+        loop {
+            let end = self
+                .buffer
+                .windows(2)
+                .position(|window| window == b"\n\n")?;
+            let event: Vec<u8> = self.buffer.drain(..end + 2).collect();
+
+            let data = String::from_utf8_lossy(&event)
+                .lines()
+                .filter_map(|line| line.strip_prefix("data:"))
+                .map(|data| data.strip_prefix(' ').unwrap_or(data))
+                .collect::<Vec<&str>>()
+                .join("\n");
+
+            // comments and keep-alives carry no data
+            if !data.is_empty() {
+                return Some(data);
+            }
+        }
+    }
+
+    fn parse_update(&mut self, data: &str) -> Result<Option<StreamedPriceUpdate>, HermesError> {
+        let price_update: PriceUpdate = serde_json::from_str(data)?;
+        let price_feeds = price_update.price_feeds()?;
+
+        let mut is_new = false;
+        for price_feed in &price_feeds {
+            let latest_publish_time = self
+                .latest_publish_times
+                .entry(price_feed.id.0)
+                .or_default();
+            if price_feed.price.publish_time > *latest_publish_time {
+                *latest_publish_time = price_feed.price.publish_time;
+                is_new = true;
+            }
+        }
+
+        // after a reconnect Hermes may replay updates that were already yielded
+        if !is_new {
+            return Ok(None);
+        }
+
+        Ok(Some(StreamedPriceUpdate {
+            update_data: price_update.update_data()?,
+            price_feeds,
+        }))
+    }
+}
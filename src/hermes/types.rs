@@ -0,0 +1,147 @@
+use crate::{
+    decode::contract_price,
+    hermes::HermesError,
+    pyth_utils::{Price, PriceFeed},
+};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use fuels::types::{Bits256, Bytes};
+use serde::{Deserialize, Serialize};
+
+/// The encoding Hermes uses for the binary section of a price update.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EncodingType {
+    #[default]
+    Hex,
+    Base64,
+}
+
+impl EncodingType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EncodingType::Hex => "hex",
+            EncodingType::Base64 => "base64",
+        }
+    }
+}
+
+/// Response body of the `/v2/updates/price/*` endpoints.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PriceUpdate {
+    pub binary: BinaryPriceUpdate,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parsed: Option<Vec<ParsedPriceUpdate>>,
+}
+
+impl PriceUpdate {
+    /// The update data, ready to be passed to `update_price_feeds`.
+    pub fn update_data(&self) -> Result<Vec<Bytes>, HermesError> {
+        self.binary.decode()
+    }
+
+    /// The parsed price feeds, converted to the contract's representation.
+    pub fn price_feeds(&self) -> Result<Vec<PriceFeed>, HermesError> {
+        self.parsed
+            .as_ref()
+            .ok_or(HermesError::MissingParsedSection)?
+            .iter()
+            .map(ParsedPriceUpdate::to_price_feed)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BinaryPriceUpdate {
+    pub encoding: EncodingType,
+    pub data: Vec<String>,
+}
+
+impl BinaryPriceUpdate {
+    pub fn decode(&self) -> Result<Vec<Bytes>, HermesError> {
+        self.data
+            .iter()
+            .map(|data| {
+                let bytes = match self.encoding {
+                    EncodingType::Hex => hex::decode(data.trim_start_matches("0x"))?,
+                    EncodingType::Base64 => BASE64_STANDARD.decode(data)?,
+                };
+                Ok(Bytes(bytes))
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParsedPriceUpdate {
+    pub id: String,
+    pub price: RpcPrice,
+    pub ema_price: RpcPrice,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<RpcPriceFeedMetadata>,
+}
+
+impl ParsedPriceUpdate {
+    pub fn price_feed_id(&self) -> Result<Bits256, HermesError> {
+        Bits256::from_hex_str(&self.id)
+            .map_err(|_| HermesError::InvalidPriceFeedId(self.id.clone()))
+    }
+
+    pub fn to_price_feed(&self) -> Result<PriceFeed, HermesError> {
+        Ok(PriceFeed {
+            ema_price: self.ema_price.to_price(),
+            id: self.price_feed_id()?,
+            price: self.price.to_price(),
+        })
+    }
+}
+
+/// A price as served by Hermes: signed exponent, UNIX `publish_time` and string encoded integers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcPrice {
+    #[serde(with = "string_number")]
+    pub price: i64,
+    #[serde(with = "string_number")]
+    pub conf: u64,
+    pub expo: i32,
+    pub publish_time: i64,
+}
+
+impl RpcPrice {
+    /// Converts to the contract's `Price`, which stores the absolute exponent and a TAI64 `publish_time`.
+    pub fn to_price(&self) -> Price {
+        contract_price(self.price, self.conf, self.expo, self.publish_time)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RpcPriceFeedMetadata {
+    #[serde(default)]
+    pub slot: Option<u64>,
+    #[serde(default)]
+    pub proof_available_time: Option<i64>,
+    #[serde(default)]
+    pub prev_publish_time: Option<i64>,
+}
+
+mod string_number {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use std::{fmt::Display, str::FromStr};
+
+    pub fn serialize<T: Display, S: Serializer>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,189 @@
+mod config;
+mod error;
+mod metrics;
+
+pub use config::{
+    deviation_bps, FeedConfig, KeeperConfig, UpdateReason, DEFAULT_METRICS_PORT,
+    DEFAULT_POLL_INTERVAL_SECONDS,
+};
+pub use error::KeeperError;
+pub use metrics::{
+    revert_error, router, FeedStaleness, Health, KeeperMetrics, KeeperObservation,
+    UNKNOWN_REVERT_ERROR,
+};
+
+use crate::{
+    accumulator::trim_update_data,
+    constants::TAI64_DIFFERENCE,
+    events::refunded_fee,
+    hermes::{EncodingType, HermesClient},
+    pyth_utils::Pyth,
+};
+use fuels::{
+    prelude::{Account, BASE_ASSET_ID},
+    types::{errors::Error, Bits256},
+};
+
+/// Keeps price feeds on-chain up to date with Hermes, within each feed's heartbeat and deviation
+/// thresholds.
+pub struct Keeper<A: Account> {
+    pyth: Pyth<A>,
+    hermes: HermesClient,
+    feeds: Vec<FeedConfig>,
+}
+
+/// A price feed the keeper updated, or attempted to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeedUpdate {
+    pub id: Bits256,
+    /// Publish time (TAI64) of the price the feed is updated to.
+    pub publish_time: u64,
+    pub reason: UpdateReason,
+}
+
+/// Outcome of a single keeper step.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum KeeperStep {
+    /// No price feed needed an update.
+    UpToDate,
+    /// `fee` is the update fee the contract kept, which excludes the refund of a transaction that
+    /// found every price feed already updated.
+    Updated {
+        updates: Vec<FeedUpdate>,
+        fee: u64,
+    },
+    /// The wallet cannot pay the update fee, so no transaction was sent.
+    InsufficientBalance {
+        balance: u64,
+        fee: u64,
+    },
+    /// The update transaction reverted with `reason`.
+    Reverted {
+        updates: Vec<FeedUpdate>,
+        reason: String,
+    },
+}
+
+impl<A: Account> Keeper<A> {
+    pub fn new(pyth: Pyth<A>, hermes: HermesClient, feeds: Vec<FeedConfig>) -> Self {
+        Self {
+            pyth,
+            hermes,
+            feeds,
+        }
+    }
+
+    pub fn pyth(&self) -> &Pyth<A> {
+        &self.pyth
+    }
+
+    pub fn feeds(&self) -> &[FeedConfig] {
+        &self.feeds
+    }
+
+    /// Fetches the latest prices from Hermes, compares them against the on-chain prices and, when
+    /// any feed needs an update, updates those feeds with `update_price_feeds_if_necessary`.
+    ///
+    /// An insufficient balance and reverts are reported as [KeeperStep]s, so the keeper can retry
+    /// on its next step; other failures are returned as errors.
+    pub async fn step(&self) -> Result<KeeperStep, KeeperError> {
+        let price_feed_ids: Vec<Bits256> = self.feeds.iter().map(|feed| feed.id).collect();
+        let latest = self
+            .hermes
+            .latest_price_updates(&price_feed_ids, EncodingType::Hex, true)
+            .await?;
+        let latest_price_feeds = latest.price_feeds()?;
+
+        let mut updates = vec![];
+        for feed in &self.feeds {
+            let Some(latest_price_feed) = latest_price_feeds
+                .iter()
+                .find(|price_feed| price_feed.id == feed.id)
+            else {
+                continue;
+            };
+
+            let on_chain = if self.pyth.price_feed_exists(feed.id).await?.value {
+                Some(self.pyth.price_unsafe(feed.id).await?.value)
+            } else {
+                None
+            };
+            if let Some(reason) = feed.update_reason(on_chain.as_ref(), &latest_price_feed.price) {
+                updates.push(FeedUpdate {
+                    id: feed.id,
+                    publish_time: latest_price_feed.price.publish_time,
+                    reason,
+                });
+            }
+        }
+        if updates.is_empty() {
+            return Ok(KeeperStep::UpToDate);
+        }
+
+        let price_feed_ids: Vec<Bits256> = updates.iter().map(|update| update.id).collect();
+        let publish_times: Vec<u64> = updates.iter().map(|update| update.publish_time).collect();
+        let update_data = trim_update_data(&latest.update_data()?, &price_feed_ids)?;
+
+        let fee = self.pyth.compute_update_fee(&update_data).await?;
+        let balance = self.pyth.wallet.get_asset_balance(&BASE_ASSET_ID).await?;
+        if balance < fee {
+            return Ok(KeeperStep::InsufficientBalance { balance, fee });
+        }
+
+        match self
+            .pyth
+            .update_price_feeds_if_necessary(fee, &price_feed_ids, &publish_times, &update_data)
+            .await
+        {
+            Ok(response) => Ok(KeeperStep::Updated {
+                updates,
+                fee: fee - refunded_fee(&response)?,
+            }),
+            Err(Error::RevertTransactionError { reason, .. }) => {
+                Ok(KeeperStep::Reverted { updates, reason })
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Reads the staleness of every price feed at the latest block, the contract's valid time
+    /// period and the wallet's balance.
+    pub async fn observe(&self) -> Result<KeeperObservation, KeeperError> {
+        let block_time = self
+            .pyth
+            .wallet
+            .try_provider()
+            .map_err(Error::from)?
+            .latest_block_time()
+            .await
+            .map_err(Error::from)?
+            .and_then(|time| u64::try_from(time.timestamp()).ok())
+            .unwrap_or_default();
+        let valid_time_period = self.pyth.valid_time_period().await?.value;
+        let balance = self.pyth.wallet.get_asset_balance(&BASE_ASSET_ID).await?;
+
+        let mut feeds = vec![];
+        for feed in &self.feeds {
+            // the contract returns 0, which precedes every TAI64 time, for price feeds it does not have
+            let staleness = self
+                .pyth
+                .latest_publish_time(feed.id)
+                .await?
+                .value
+                .checked_sub(TAI64_DIFFERENCE)
+                .map(|publish_time| block_time.saturating_sub(publish_time));
+
+            feeds.push(FeedStaleness {
+                id: feed.id,
+                staleness,
+            });
+        }
+
+        Ok(KeeperObservation {
+            block_time,
+            valid_time_period,
+            balance,
+            feeds,
+        })
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    constants::{BETA_5_PYTH_CONTRACT_ID, BETA_5_URL},
+    hermes::DEFAULT_HERMES_URL,
+    pyth_utils::Price,
+};
+use fuels::types::{Bits256, ContractId};
+use serde::{de, Deserialize, Deserializer};
+use std::{net::SocketAddr, str::FromStr, time::Duration};
+
+pub const DEFAULT_POLL_INTERVAL_SECONDS: u64 = 5;
+pub const DEFAULT_METRICS_PORT: u16 = 9090;
+
+/// Configuration of the `pyth-keeper` binary, read from JSON.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct KeeperConfig {
+    #[serde(default = "default_provider_url")]
+    pub provider_url: String,
+    #[serde(
+        default = "default_contract_id",
+        deserialize_with = "deserialize_contract_id"
+    )]
+    pub contract_id: ContractId,
+    #[serde(default = "default_hermes_url")]
+    pub hermes_url: String,
+    #[serde(default = "default_poll_interval_seconds")]
+    pub poll_interval_seconds: u64,
+    /// Address serving the `/metrics` and `/health` endpoints.
+    #[serde(default = "default_metrics_address")]
+    pub metrics_address: SocketAddr,
+    pub feeds: Vec<FeedConfig>,
+}
+
+/// A price feed to keep up to date.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+pub struct FeedConfig {
+    #[serde(deserialize_with = "deserialize_price_feed_id")]
+    pub id: Bits256,
+    /// Update once the latest price is this much newer than the on-chain price.
+    pub heartbeat_seconds: u64,
+    /// Update once the latest price deviates this much from the on-chain price, in basis points.
+    pub deviation_bps: u64,
+}
+
+/// Why a price feed needs to be updated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UpdateReason {
+    /// The price feed does not exist on-chain.
+    Missing,
+    /// The on-chain price is older than the heartbeat.
+    Heartbeat,
+    /// The latest price deviates from the on-chain price by at least the threshold.
+    Deviation,
+}
+
+impl KeeperConfig {
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_secs(self.poll_interval_seconds)
+    }
+}
+
+impl FeedConfig {
+    /// Whether the `on_chain` price, if any, should be replaced by the `latest` price. Both prices
+    /// are in the contract's representation.
+    pub fn update_reason(&self, on_chain: Option<&Price>, latest: &Price) -> Option<UpdateReason> {
+        let Some(on_chain) = on_chain else {
+            return Some(UpdateReason::Missing);
+        };
+        if latest.publish_time <= on_chain.publish_time {
+            return None;
+        }
+
+        if latest.publish_time - on_chain.publish_time >= self.heartbeat_seconds {
+            Some(UpdateReason::Heartbeat)
+        } else if deviation_bps(on_chain, latest) >= self.deviation_bps {
+            Some(UpdateReason::Deviation)
+        } else {
+            None
+        }
+    }
+}
+
+/// The deviation of `latest` from `on_chain` in basis points, saturating when the exponents differ
+/// or the on-chain price is zero.
+pub fn deviation_bps(on_chain: &Price, latest: &Price) -> u64 {
+    // the contract stores the signed price as its two's complement bits
+    let on_chain_price = i128::from(on_chain.price as i64);
+    let latest_price = i128::from(latest.price as i64);
+
+    if on_chain.exponent != latest.exponent {
+        return u64::MAX;
+    }
+    if on_chain_price == 0 {
+        return if latest_price == 0 { 0 } else { u64::MAX };
+    }
+
+    let deviation = (latest_price - on_chain_price).abs() * 10_000 / on_chain_price.abs();
+    u64::try_from(deviation).unwrap_or(u64::MAX)
+}
+
+fn default_provider_url() -> String {
+    BETA_5_URL.to_string()
+}
+
+fn default_contract_id() -> ContractId {
+    BETA_5_PYTH_CONTRACT_ID
+}
+
+fn default_hermes_url() -> String {
+    DEFAULT_HERMES_URL.to_string()
+}
+
+fn default_poll_interval_seconds() -> u64 {
+    DEFAULT_POLL_INTERVAL_SECONDS
+}
+
+fn default_metrics_address() -> SocketAddr {
+    SocketAddr::from(([0, 0, 0, 0], DEFAULT_METRICS_PORT))
+}
+
+fn deserialize_contract_id<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<ContractId, D::Error> {
+    let id = String::deserialize(deserializer)?;
+
+    ContractId::from_str(&id).map_err(|_| de::Error::custom(format!("invalid contract id `{id}`")))
+}
+
+fn deserialize_price_feed_id<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Bits256, D::Error> {
+    let id = String::deserialize(deserializer)?;
+
+    Bits256::from_hex_str(&id)
+        .map_err(|_| de::Error::custom(format!("invalid price feed id `{id}`")))
+}
@@ -0,0 +1,15 @@
+use crate::{decode::DecodeError, hermes::HermesError, pyth_utils::PythSdkError};
+use fuels::types::errors::Error;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum KeeperError {
+    #[error(transparent)]
+    Hermes(#[from] HermesError),
+    #[error(transparent)]
+    Fuels(#[from] Error),
+    #[error(transparent)]
+    Pyth(#[from] PythSdkError),
+    #[error(transparent)]
+    Decode(#[from] DecodeError),
+}
@@ -0,0 +1,310 @@
+use super::{KeeperStep, UpdateReason};
+use axum::{
+    extract::State,
+    http::{header::CONTENT_TYPE, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use fuels::types::Bits256;
+use prometheus::{
+    IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry, TextEncoder, TEXT_FORMAT,
+};
+use serde_json::Value;
+use std::{
+    collections::HashSet,
+    fmt::Display,
+    sync::{Arc, OnceLock, RwLock},
+};
+
+/// Label of reverts whose reason is not a contract error variant.
+pub const UNKNOWN_REVERT_ERROR: &str = "Unknown";
+
+const PYTH_CONTRACT_ABI: &str =
+    include_str!("../../pyth-contract/out/debug/pyth-contract-abi.json");
+
+/// On-chain state of the keeper's price feeds and wallet, read by `Keeper::observe`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeeperObservation {
+    /// UNIX time of the latest block.
+    pub block_time: u64,
+    pub valid_time_period: u64,
+    /// Base asset balance of the keeper's wallet.
+    pub balance: u64,
+    pub feeds: Vec<FeedStaleness>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FeedStaleness {
+    pub id: Bits256,
+    /// Seconds from the on-chain publish time to the block time, `None` when the price feed does
+    /// not exist on-chain.
+    pub staleness: Option<u64>,
+}
+
+/// Health of the keeper's price feeds, as of the latest recorded observation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Health {
+    /// No observation has been recorded yet.
+    Unknown,
+    Healthy,
+    /// These price feeds are older than the valid time period, or do not exist on-chain.
+    Stale(Vec<Bits256>),
+    /// The latest observation failed with this error, leaving the price feeds' state unknown.
+    ObservationFailed(String),
+}
+
+/// Prometheus metrics of a keeper, along with the health of its price feeds.
+pub struct KeeperMetrics {
+    registry: Registry,
+    staleness: IntGaugeVec,
+    valid_time_period: IntGauge,
+    updates: IntCounterVec,
+    fees: IntCounter,
+    reverts: IntCounterVec,
+    balance: IntGauge,
+    health: RwLock<Health>,
+}
+
+impl FeedStaleness {
+    /// Whether the price feed is older than `valid_time_period`, in which case the contract's
+    /// `price` reverts with `OutdatedPrice`.
+    pub fn is_stale(&self, valid_time_period: u64) -> bool {
+        self.staleness
+            .is_none_or(|staleness| staleness > valid_time_period)
+    }
+}
+
+impl KeeperMetrics {
+    pub fn new() -> Result<Self, prometheus::Error> {
+        let registry = Registry::new();
+
+        let staleness = IntGaugeVec::new(
+            Opts::new(
+                "pyth_keeper_feed_staleness_seconds",
+                "Seconds from the on-chain publish time of a price feed to the latest block time",
+            ),
+            &["feed"],
+        )?;
+        let valid_time_period = IntGauge::new(
+            "pyth_keeper_valid_time_period_seconds",
+            "Valid time period of the contract",
+        )?;
+        let updates = IntCounterVec::new(
+            Opts::new(
+                "pyth_keeper_updates_total",
+                "Price feed updates sent, by update reason",
+            ),
+            &["reason"],
+        )?;
+        let fees = IntCounter::new(
+            "pyth_keeper_fees_spent_total",
+            "Update fees paid, in the base asset",
+        )?;
+        let reverts = IntCounterVec::new(
+            Opts::new(
+                "pyth_keeper_reverts_total",
+                "Reverted update transactions, by contract error",
+            ),
+            &["error"],
+        )?;
+        let balance = IntGauge::new(
+            "pyth_keeper_wallet_balance",
+            "Base asset balance of the keeper wallet",
+        )?;
+
+        registry.register(Box::new(staleness.clone()))?;
+        registry.register(Box::new(valid_time_period.clone()))?;
+        registry.register(Box::new(updates.clone()))?;
+        registry.register(Box::new(fees.clone()))?;
+        registry.register(Box::new(reverts.clone()))?;
+        registry.register(Box::new(balance.clone()))?;
+
+        Ok(Self {
+            registry,
+            staleness,
+            valid_time_period,
+            updates,
+            fees,
+            reverts,
+            balance,
+            health: RwLock::new(Health::Unknown),
+        })
+    }
+
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    /// Counts the updates, fee and revert of a keeper step.
+    pub fn record_step(&self, step: &KeeperStep) {
+        match step {
+            KeeperStep::UpToDate => {}
+            KeeperStep::Updated { updates, fee } => {
+                for update in updates {
+                    self.updates
+                        .with_label_values(&[update_reason_label(update.reason)])
+                        .inc();
+                }
+                self.fees.inc_by(*fee);
+            }
+            KeeperStep::InsufficientBalance { balance, .. } => self.balance.set(gauge(*balance)),
+            KeeperStep::Reverted { reason, .. } => {
+                self.reverts
+                    .with_label_values(&[revert_error(reason)])
+                    .inc();
+            }
+        }
+    }
+
+    /// Sets the staleness and balance gauges and the health of the price feeds.
+    pub fn record_observation(&self, observation: &KeeperObservation) {
+        self.valid_time_period
+            .set(gauge(observation.valid_time_period));
+        self.balance.set(gauge(observation.balance));
+
+        for feed in &observation.feeds {
+            let id = hex_id(&feed.id);
+            match feed.staleness {
+                Some(staleness) => self
+                    .staleness
+                    .with_label_values(&[&id])
+                    .set(gauge(staleness)),
+                // a missing price feed has no publish time to be stale from
+                None => {
+                    let _ = self.staleness.remove_label_values(&[&id]);
+                }
+            }
+        }
+
+        let stale_feeds: Vec<Bits256> = observation
+            .feeds
+            .iter()
+            .filter(|feed| feed.is_stale(observation.valid_time_period))
+            .map(|feed| feed.id)
+            .collect();
+        *self.health.write().unwrap() = if stale_feeds.is_empty() {
+            Health::Healthy
+        } else {
+            Health::Stale(stale_feeds)
+        };
+    }
+
+    /// Sets the health to [Health::ObservationFailed], as the price feeds may have gone stale since
+    /// the last successful observation.
+    pub fn record_observation_error(&self, error: &impl Display) {
+        *self.health.write().unwrap() = Health::ObservationFailed(error.to_string());
+    }
+
+    pub fn health(&self) -> Health {
+        self.health.read().unwrap().clone()
+    }
+
+    /// The metrics in the Prometheus text format.
+    pub fn encode(&self) -> Result<String, prometheus::Error> {
+        TextEncoder::new().encode_to_string(&self.registry.gather())
+    }
+}
+
+/// A router serving the metrics at `/metrics` and the health at `/health`, which fails with
+/// `503 Service Unavailable` unless the latest observation succeeded and every price feed is
+/// within the valid time period.
+pub fn router(metrics: Arc<KeeperMetrics>) -> Router {
+    Router::new()
+        .route("/metrics", get(serve_metrics))
+        .route("/health", get(serve_health))
+        .with_state(metrics)
+}
+
+/// The contract error variant `reason` names, or [UNKNOWN_REVERT_ERROR].
+///
+/// Reverts with a logged error enum, such as `PythError`, have the variant's name as their reason.
+/// Only the variants of the enums the contract's ABI logs are labels, which keeps the label values
+/// bounded by the contract's errors.
+pub fn revert_error(reason: &str) -> &str {
+    if contract_error_variants().contains(reason) {
+        reason
+    } else {
+        UNKNOWN_REVERT_ERROR
+    }
+}
+
+/// The variant names of every enum logged by the contract.
+fn contract_error_variants() -> &'static HashSet<String> {
+    static VARIANTS: OnceLock<HashSet<String>> = OnceLock::new();
+
+    VARIANTS.get_or_init(|| {
+        let abi: Value = serde_json::from_str(PYTH_CONTRACT_ABI).expect("invalid contract ABI");
+        let logged_types: HashSet<u64> = abi["loggedTypes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|logged_type| logged_type["loggedType"]["type"].as_u64())
+            .collect();
+
+        abi["types"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter(|abi_type| {
+                abi_type["type"]
+                    .as_str()
+                    .is_some_and(|name| name.starts_with("enum "))
+                    && abi_type["typeId"]
+                        .as_u64()
+                        .is_some_and(|type_id| logged_types.contains(&type_id))
+            })
+            .flat_map(|abi_type| abi_type["components"].as_array().into_iter().flatten())
+            .filter_map(|variant| variant["name"].as_str().map(str::to_string))
+            .collect()
+    })
+}
+
+async fn serve_metrics(State(metrics): State<Arc<KeeperMetrics>>) -> Response {
+    match metrics.encode() {
+        Ok(body) => ([(CONTENT_TYPE, TEXT_FORMAT)], body).into_response(),
+        Err(error) => (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()).into_response(),
+    }
+}
+
+async fn serve_health(State(metrics): State<Arc<KeeperMetrics>>) -> Response {
+    match metrics.health() {
+        Health::Healthy => (StatusCode::OK, "ok").into_response(),
+        Health::Unknown => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            "no price feeds observed yet",
+        )
+            .into_response(),
+        Health::Stale(ids) => {
+            let ids: Vec<String> = ids.iter().map(hex_id).collect();
+
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                format!("stale price feeds: {}", ids.join(", ")),
+            )
+                .into_response()
+        }
+        Health::ObservationFailed(error) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            format!("price feeds not observed: {error}"),
+        )
+            .into_response(),
+    }
+}
+
+fn update_reason_label(reason: UpdateReason) -> &'static str {
+    match reason {
+        UpdateReason::Missing => "missing",
+        UpdateReason::Heartbeat => "heartbeat",
+        UpdateReason::Deviation => "deviation",
+    }
+}
+
+fn hex_id(id: &Bits256) -> String {
+    format!("0x{}", hex::encode(id.0))
+}
+
+// the gauges are signed, amounts beyond i64::MAX are capped
+fn gauge(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
@@ -1,2 +1,15 @@
+pub mod accumulator;
+pub mod batch_attestation;
 pub mod constants;
+pub mod decode;
+pub mod deploy;
+pub mod events;
+pub mod fee;
+pub mod governance;
+pub mod hermes;
+pub mod keeper;
+pub mod network;
 pub mod pyth_utils;
+#[cfg(feature = "test-utils")]
+pub mod test_utils;
+pub mod wormhole;
@@ -0,0 +1,9 @@
+mod config;
+mod error;
+
+pub use config::{DataSourceConfig, DataSourceSet, Network, NetworkConfig};
+pub use error::NetworkError;
+
+pub(crate) use config::{
+    decode_vaa, deserialize_bits256, deserialize_optional_bits256, serialize_bits256,
+};
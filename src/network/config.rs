@@ -0,0 +1,240 @@
+use super::NetworkError;
+use crate::{
+    constants::{
+        DEFAULT_SINGLE_UPDATE_FEE, DEFAULT_VALID_TIME_PERIOD, GOVERNANCE_DATA_SOURCE_CHAIN_ID,
+        GOVERNANCE_DATA_SOURCE_EMITTER_ADDRESS, GUARDIAN_SET_UPGRADE_4_VAA,
+    },
+    decode::DecodeError,
+    hermes::DEFAULT_HERMES_URL,
+    pyth_utils::DataSource,
+    wormhole::{GuardianSetUpgrade, Vaa},
+};
+use fuels::types::{Bits256, Bytes};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::path::Path;
+
+const MAINNET: &str = include_str!("../../networks/mainnet.toml");
+const TESTNET: &str = include_str!("../../networks/testnet.toml");
+const LOCAL: &str = include_str!("../../networks/local.toml");
+
+/// The parameters of a Pyth deployment on a Fuel network, read from TOML or JSON. The presets are
+/// the files of the `networks` directory.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct NetworkConfig {
+    pub name: String,
+    pub provider_url: String,
+    #[serde(default = "default_hermes_url")]
+    pub hermes_url: String,
+    #[serde(default = "default_single_update_fee")]
+    pub single_update_fee: u64,
+    #[serde(default = "default_valid_time_period_seconds")]
+    pub valid_time_period_seconds: u64,
+    /// Hex encoded guardian set upgrade VAA passed to the `constructor`. Defaults to the upgrade to
+    /// guardian set 4.
+    #[serde(
+        default = "default_guardian_set_upgrade_vaa",
+        deserialize_with = "deserialize_vaa"
+    )]
+    pub guardian_set_upgrade_vaa: Bytes,
+    /// Price feeds of interest on the network, such as the ones kept up to date by a keeper.
+    #[serde(default, deserialize_with = "deserialize_price_feed_ids")]
+    pub price_feed_ids: Vec<Bits256>,
+    pub data_sources: DataSourceSet,
+    /// Emitter of the governance VAAs the contract executes. Defaults to Pyth's mainnet governance.
+    #[serde(default = "default_governance_data_source")]
+    pub governance_data_source: DataSourceConfig,
+}
+
+/// The emitters whose price updates the contract accepts, in the order they are passed to the
+/// `constructor`.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct DataSourceSet(pub Vec<DataSourceConfig>);
+
+/// A data source with a hex encoded emitter address.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct DataSourceConfig {
+    pub chain_id: u16,
+    #[serde(
+        deserialize_with = "deserialize_bits256",
+        serialize_with = "serialize_bits256"
+    )]
+    pub emitter_address: Bits256,
+}
+
+/// A built-in network preset.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum Network {
+    #[serde(rename = "mainnet")]
+    Mainnet,
+    #[default]
+    #[serde(rename = "testnet", alias = "beta-5")]
+    Testnet,
+    #[serde(rename = "local")]
+    Local,
+}
+
+impl NetworkConfig {
+    /// Pyth stable data sources on the Fuel mainnet.
+    pub fn mainnet() -> Self {
+        Self::from_toml(MAINNET).expect("the mainnet preset is valid")
+    }
+
+    /// Pyth stable and beta data sources on the Fuel Beta-5 testnet.
+    pub fn testnet() -> Self {
+        Self::from_toml(TESTNET).expect("the testnet preset is valid")
+    }
+
+    /// A local `fuel-core` node with local emitters, upgrading to the guardian set whose key is
+    /// public, `LocalGuardianSet::local()` with the `test-utils` feature.
+    pub fn local() -> Self {
+        Self::from_toml(LOCAL).expect("the local preset is valid")
+    }
+
+    pub fn from_toml(toml: &str) -> Result<Self, NetworkError> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, NetworkError> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Reads a network from a `.json` file, or from TOML for any other extension.
+    pub fn from_file(path: &Path) -> Result<Self, NetworkError> {
+        let contents = std::fs::read_to_string(path).map_err(|source| NetworkError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Self::from_json(&contents),
+            _ => Self::from_toml(&contents),
+        }
+    }
+
+    pub fn with_guardian_set_upgrade_vaa(mut self, guardian_set_upgrade_vaa: Bytes) -> Self {
+        self.guardian_set_upgrade_vaa = guardian_set_upgrade_vaa;
+        self
+    }
+
+    /// Decodes the upgrade carried by the guardian set upgrade VAA.
+    pub fn guardian_set_upgrade(&self) -> Result<GuardianSetUpgrade, DecodeError> {
+        GuardianSetUpgrade::parse(&Vaa::parse(&self.guardian_set_upgrade_vaa.0)?.payload)
+    }
+}
+
+impl DataSourceSet {
+    pub fn data_sources(&self) -> Vec<DataSource> {
+        self.0.iter().map(DataSourceConfig::data_source).collect()
+    }
+
+    pub fn contains(&self, data_source: &DataSource) -> bool {
+        self.0.contains(&DataSourceConfig::from(data_source))
+    }
+}
+
+impl FromIterator<DataSourceConfig> for DataSourceSet {
+    fn from_iter<I: IntoIterator<Item = DataSourceConfig>>(iter: I) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl DataSourceConfig {
+    pub fn data_source(&self) -> DataSource {
+        DataSource {
+            chain_id: self.chain_id,
+            emitter_address: self.emitter_address,
+        }
+    }
+}
+
+impl From<&DataSource> for DataSourceConfig {
+    fn from(data_source: &DataSource) -> Self {
+        Self {
+            chain_id: data_source.chain_id,
+            emitter_address: data_source.emitter_address,
+        }
+    }
+}
+
+impl Network {
+    pub fn config(&self) -> NetworkConfig {
+        match self {
+            Network::Mainnet => NetworkConfig::mainnet(),
+            Network::Testnet => NetworkConfig::testnet(),
+            Network::Local => NetworkConfig::local(),
+        }
+    }
+}
+
+/// Decodes a hex encoded VAA, with or without `0x` prefix.
+pub(crate) fn decode_vaa(encoded: &str) -> Result<Vec<u8>, hex::FromHexError> {
+    let encoded = encoded.trim();
+
+    hex::decode(encoded.strip_prefix("0x").unwrap_or(encoded))
+}
+
+fn default_hermes_url() -> String {
+    DEFAULT_HERMES_URL.to_string()
+}
+
+fn default_single_update_fee() -> u64 {
+    DEFAULT_SINGLE_UPDATE_FEE
+}
+
+fn default_valid_time_period_seconds() -> u64 {
+    DEFAULT_VALID_TIME_PERIOD
+}
+
+fn default_guardian_set_upgrade_vaa() -> Bytes {
+    Bytes(decode_vaa(GUARDIAN_SET_UPGRADE_4_VAA).expect("the guardian set 4 upgrade is hex"))
+}
+
+fn default_governance_data_source() -> DataSourceConfig {
+    DataSourceConfig {
+        chain_id: GOVERNANCE_DATA_SOURCE_CHAIN_ID,
+        emitter_address: Bits256::from_hex_str(GOVERNANCE_DATA_SOURCE_EMITTER_ADDRESS)
+            .expect("the governance emitter address is a b256"),
+    }
+}
+
+fn deserialize_vaa<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Bytes, D::Error> {
+    let encoded = String::deserialize(deserializer)?;
+
+    decode_vaa(&encoded)
+        .map(Bytes)
+        .map_err(|_| de::Error::custom("the guardian set upgrade VAA is not hex encoded"))
+}
+
+fn deserialize_price_feed_ids<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Vec<Bits256>, D::Error> {
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|hex| {
+            Bits256::from_hex_str(hex)
+                .map_err(|_| de::Error::custom(format!("invalid price feed id `{hex}`")))
+        })
+        .collect()
+}
+
+pub(crate) fn deserialize_optional_bits256<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Bits256>, D::Error> {
+    deserialize_bits256(deserializer).map(Some)
+}
+
+pub(crate) fn deserialize_bits256<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Bits256, D::Error> {
+    let hex = String::deserialize(deserializer)?;
+
+    Bits256::from_hex_str(&hex).map_err(|_| de::Error::custom(format!("invalid b256 `{hex}`")))
+}
+
+pub(crate) fn serialize_bits256<S: Serializer>(
+    bits: &Bits256,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&format!("0x{}", hex::encode(bits.0)))
+}
@@ -0,0 +1,16 @@
+use std::{io, path::PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum NetworkError {
+    #[error("failed to read `{path}`")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+    #[error("invalid TOML network config")]
+    Toml(#[from] toml::de::Error),
+    #[error("invalid JSON network config")]
+    Json(#[from] serde_json::Error),
+}
@@ -1,38 +1,51 @@
+mod error;
+
+pub use error::PythSdkError;
+
 use crate::constants::{
     BTC_USD_PRICE_FEED_ID, DEFAULT_SINGLE_UPDATE_FEE, ETH_USD_PRICE_FEED_ID,
     GUARDIAN_SET_UPGRADE_3_VAA, GUARDIAN_SET_UPGRADE_4_VAA, PYTH_CONTRACT_BINARY_PATH,
-    TEST_ACCUMULATOR_UPDATE_DATA, TEST_BATCH_UPDATE_DATA, UNI_USD_PRICE_FEED_ID,
-    USDC_USD_PRICE_FEED_ID,
-};
-use base64::{
-    engine::general_purpose,
-    prelude::{Engine, BASE64_STANDARD},
+    PYTH_CONTRACT_STORAGE_SLOTS_FILE, TAI64_DIFFERENCE, TEST_ACCUMULATOR_UPDATE_DATA,
+    TEST_BATCH_UPDATE_DATA, UNI_USD_PRICE_FEED_ID, USDC_USD_PRICE_FEED_ID,
 };
+use crate::events::{decode_logs, EventCallResponse, PythEvent};
+use crate::fee::compute_update_fee;
+use crate::hermes::{HermesClient, HermesConfig, HermesError};
+use crate::network::NetworkConfig;
+use base64::prelude::{Engine, BASE64_STANDARD};
 use fuels::{
-    prelude::{abigen, CallParameters, Contract, LoadConfiguration, TxPolicies, WalletUnlocked},
+    core::Configurables,
+    prelude::{
+        abigen, Account, CallParameters, Contract, CreateTransaction, CreateTransactionBuilder,
+        Provider, SettableContract, StorageConfiguration, Transaction, TxDependencyExtension,
+        TxPolicies, WalletUnlocked,
+    },
     programs::call_response::FuelCallResponse,
-    types::{errors::Error, Address, Bits256, Bytes, Identity},
+    tx::{Bytes32, Receipt, StorageSlot, TxId},
+    types::{
+        errors::Error, transaction_builders::BuildableTransaction, Address, Bits256, Bytes,
+        ContractId, Identity,
+    },
 };
 use rand::Rng;
-use reqwest;
-use serde_json;
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Mutex};
 
 abigen!(Contract(
     name = "PythOracleContract",
     abi = "pyth-contract/out/debug/pyth-contract-abi.json"
 ));
 
-pub struct Pyth {
-    pub instance: PythOracleContract<WalletUnlocked>,
-    pub wallet: WalletUnlocked,
+/// Client of the Pyth contract, sending transactions from `wallet`, which can be any account such
+/// as a predicate or an external signer.
+pub struct Pyth<A: Account = WalletUnlocked> {
+    pub instance: PythOracleContract<A>,
+    pub wallet: A,
+    single_update_fee_cache: Mutex<Option<u64>>,
 }
 
 pub async fn update_data_bytes(
-    price_feed_ids: Option<Vec<&str>>,
-) -> Result<Vec<Bytes>, Box<dyn std::error::Error>> {
-    let c = reqwest::Client::new();
-
+    price_feed_ids: Option<Vec<Bits256>>,
+) -> Result<Vec<Bytes>, HermesError> {
     let price_feed_ids = price_feed_ids.unwrap_or_else(|| {
         vec![
             ETH_USD_PRICE_FEED_ID,
@@ -42,34 +55,9 @@ pub async fn update_data_bytes(
         ]
     });
 
-    let mut ids_query_part = String::new();
-    for (index, id) in price_feed_ids.iter().enumerate() {
-        if index > 0 {
-            ids_query_part.push('&');
-        }
-        ids_query_part.push_str(&format!("ids[]={}", id));
-    }
-
-    let req_url = format!(
-        "https://hermes.pyth.network/api/latest_vaas?{}",
-        ids_query_part
-    );
-    let body = c.get(&req_url).send().await?.text().await?;
-    let response: Vec<&str> = serde_json::from_str(&body)?;
-
-    let bytes_data: Vec<Bytes> = response
-        .iter()
-        .map(|data| {
-            Bytes(
-                general_purpose::STANDARD
-                    .decode::<&str>(data)
-                    .unwrap()
-                    .to_owned(),
-            )
-        })
-        .collect();
-
-    Ok(bytes_data)
+    HermesClient::new(HermesConfig::default())?
+        .latest_update_data(&price_feed_ids)
+        .await
 }
 
 pub fn test_batch_update_data_bytes() -> Vec<Bytes> {
@@ -87,28 +75,223 @@ pub fn test_accumulator_update_data_bytes() -> Vec<Bytes> {
     )]
 }
 
-impl Pyth {
-    pub async fn price(&self, price_feed_id: Bits256) -> Result<FuelCallResponse<Price>, Error> {
+impl<A: Account> Pyth<A> {
+    pub async fn deploy(wallet: A) -> Result<Self, Error> {
+        let mut rng = rand::thread_rng();
+        let salt = rng.gen::<[u8; 32]>();
+
+        Self::deploy_with_salt(wallet, salt).await
+    }
+
+    /// Deploys the contract with `salt`, at the id [Pyth::deployment_contract_id] returns.
+    pub async fn deploy_with_salt(wallet: A, salt: [u8; 32]) -> Result<Self, Error> {
+        Ok(Self::deploy_with_salt_and_tx_id(wallet, salt).await?.0)
+    }
+
+    /// Deploys the contract like [Pyth::deploy_with_salt], also returning the id of the create
+    /// transaction.
+    pub async fn deploy_with_salt_and_tx_id(
+        wallet: A,
+        salt: [u8; 32],
+    ) -> Result<(Self, TxId), Error> {
+        let tx = Self::deployment_transaction(&wallet, salt).await?;
+        let provider = wallet.try_provider()?;
+        let tx_id = tx.id(provider.chain_id());
+        provider
+            .send_transaction_and_await_commit(tx)
+            .await?
+            .check(None)?;
+
+        let contract_id = load_contract(Address::from(wallet.address()), salt)?
+            .contract
+            .contract_id();
+        Ok((
+            Self {
+                instance: PythOracleContract::new(contract_id, wallet.clone()),
+                wallet,
+                single_update_fee_cache: Mutex::new(None),
+            },
+            tx_id,
+        ))
+    }
+
+    /// The create transaction deploying the contract with `salt`, signed and funded by `wallet`
+    /// but not sent, so that it can also be dry run.
+    pub async fn deployment_transaction(
+        wallet: &A,
+        salt: [u8; 32],
+    ) -> Result<CreateTransaction, Error> {
+        let contract = load_contract(Address::from(wallet.address()), salt)?;
+        let mut tb = CreateTransactionBuilder::prepare_contract_deployment(
+            contract.binary,
+            contract.contract.contract_id(),
+            contract.contract.state_root(),
+            salt.into(),
+            contract.storage_slots,
+            TxPolicies::default().with_gas_price(1),
+        );
+        wallet.add_witnesses(&mut tb)?;
+        wallet.adjust_for_fee(&mut tb, 0).await?;
+
+        tb.build(wallet.try_provider()?).await
+    }
+
+    /// Connects to the Pyth contract deployed at `contract_id`, sending transactions from `wallet`.
+    ///
+    /// Fails with [PythSdkError::NotPythContract] if the contract does not answer the
+    /// `valid_time_period` and `current_guardian_set_index` methods.
+    pub async fn connect(contract_id: ContractId, wallet: A) -> Result<Self, PythSdkError> {
+        let pyth = Self {
+            instance: PythOracleContract::new(contract_id, wallet.clone()),
+            wallet,
+            single_update_fee_cache: Mutex::new(None),
+        };
+        pyth.check_abi()
+            .await
+            .map_err(|source| PythSdkError::NotPythContract {
+                contract_id,
+                source,
+            })?;
+
+        Ok(pyth)
+    }
+
+    async fn check_abi(&self) -> Result<(), Error> {
+        self.valid_time_period().await?;
+        self.current_guardian_set_index().await?;
+
+        Ok(())
+    }
+
+    /// Decodes the events this contract logged in `receipts`, such as the receipts of a
+    /// transaction fetched by an indexer.
+    pub fn decode_logs(&self, receipts: &[Receipt]) -> Result<Vec<PythEvent>, Error> {
+        decode_logs(&self.instance.log_decoder(), receipts)
+    }
+
+    // PythCore
+
+    pub async fn ema_price(
+        &self,
+        price_feed_id: Bits256,
+    ) -> Result<FuelCallResponse<Price>, Error> {
         self.instance
             .methods()
-            .price(price_feed_id)
+            .ema_price(price_feed_id)
             .simulate()
             .await
     }
 
-    pub async fn update_price_feeds(
+    pub async fn ema_price_no_older_than(
+        &self,
+        time_period: u64,
+        price_feed_id: Bits256,
+    ) -> Result<FuelCallResponse<Price>, Error> {
+        self.instance
+            .methods()
+            .ema_price_no_older_than(time_period, price_feed_id)
+            .simulate()
+            .await
+    }
+
+    pub async fn ema_price_unsafe(
+        &self,
+        price_feed_id: Bits256,
+    ) -> Result<FuelCallResponse<Price>, Error> {
+        self.instance
+            .methods()
+            .ema_price_unsafe(price_feed_id)
+            .simulate()
+            .await
+    }
+
+    /// Parses the price feeds of `update_data` published between `min_publish_time` and
+    /// `max_publish_time` (TAI64, inclusive), paying `fee`.
+    pub async fn parse_price_feed_update_data(
         &self,
         fee: u64,
+        max_publish_time: u64,
+        min_publish_time: u64,
+        price_feed_ids: &[Bits256],
         update_data: &[Bytes],
-    ) -> Result<FuelCallResponse<()>, Error> {
+    ) -> Result<FuelCallResponse<Vec<PriceFeed>>, Error> {
         self.instance
             .methods()
-            .update_price_feeds(update_data.to_vec())
+            .parse_price_feed_updates(
+                max_publish_time,
+                min_publish_time,
+                price_feed_ids.to_vec(),
+                update_data.to_vec(),
+            )
             .call_params(CallParameters::default().with_amount(fee))?
             .call()
             .await
     }
 
+    /// Fetches the update data published at `min_publish_time` from Hermes and parses the price
+    /// feeds published between `min_publish_time` and `max_publish_time` (UNIX seconds, inclusive),
+    /// paying the required fee. The price feeds are returned in the order of `price_feed_ids`.
+    pub async fn parse_price_feed_updates(
+        &self,
+        hermes: &HermesClient,
+        price_feed_ids: &[Bits256],
+        min_publish_time: u64,
+        max_publish_time: u64,
+    ) -> Result<Vec<PriceFeed>, PythSdkError> {
+        let update_data = hermes
+            .update_data_at(min_publish_time, price_feed_ids)
+            .await?;
+        let fee = self.update_fee(&update_data).await?.value;
+
+        let price_feeds = self
+            .parse_price_feed_update_data(
+                fee,
+                max_publish_time + TAI64_DIFFERENCE,
+                min_publish_time + TAI64_DIFFERENCE,
+                price_feed_ids,
+                &update_data,
+            )
+            .await?
+            .value;
+
+        Ok(price_feed_ids
+            .iter()
+            .filter_map(|id| price_feeds.iter().find(|price_feed| price_feed.id == *id))
+            .cloned()
+            .collect())
+    }
+
+    pub async fn price(&self, price_feed_id: Bits256) -> Result<FuelCallResponse<Price>, Error> {
+        self.instance
+            .methods()
+            .price(price_feed_id)
+            .simulate()
+            .await
+    }
+
+    pub async fn price_no_older_than(
+        &self,
+        time_period: u64,
+        price_feed_id: Bits256,
+    ) -> Result<FuelCallResponse<Price>, Error> {
+        self.instance
+            .methods()
+            .price_no_older_than(time_period, price_feed_id)
+            .simulate()
+            .await
+    }
+
+    pub async fn price_unsafe(
+        &self,
+        price_feed_id: Bits256,
+    ) -> Result<FuelCallResponse<Price>, Error> {
+        self.instance
+            .methods()
+            .price_unsafe(price_feed_id)
+            .simulate()
+            .await
+    }
+
     pub async fn update_fee(&self, update_data: &[Bytes]) -> Result<FuelCallResponse<u64>, Error> {
         self.instance
             .methods()
@@ -117,46 +300,278 @@ impl Pyth {
             .await
     }
 
+    /// Computes the fee `update_fee` would return for `update_data` locally, with the contract's
+    /// `single_update_fee` read once and cached.
+    pub async fn compute_update_fee(&self, update_data: &[Bytes]) -> Result<u64, PythSdkError> {
+        let single_update_fee = self.cached_single_update_fee().await?;
+
+        Ok(compute_update_fee(update_data, single_update_fee)?)
+    }
+
+    pub async fn update_price_feeds(
+        &self,
+        fee: u64,
+        update_data: &[Bytes],
+    ) -> Result<EventCallResponse<UpdatedPriceFeedsEvent>, Error> {
+        let response = self
+            .instance
+            .methods()
+            .update_price_feeds(update_data.to_vec())
+            .call_params(CallParameters::default().with_amount(fee))?
+            .append_variable_outputs(1)
+            .call()
+            .await?;
+
+        EventCallResponse::from_response(response)
+    }
+
+    /// Updates the price feeds in a transaction signed by the wallet of `self` and funded by
+    /// `fee_payer`, which pays both the update fee and the transaction fee in the base asset.
+    ///
+    /// Every input of the transaction belongs to `fee_payer`, which is therefore the sender the
+    /// contract refunds, while the wallet of `self` only adds its signature and needs no balance.
+    pub async fn update_price_feeds_with_fee_payer<F: Account>(
+        &self,
+        fee_payer: F,
+        fee: u64,
+        update_data: &[Bytes],
+    ) -> Result<EventCallResponse<UpdatedPriceFeedsEvent>, Error> {
+        let call_handler = self
+            .instance
+            .with_account(fee_payer.clone())?
+            .methods()
+            .update_price_feeds(update_data.to_vec())
+            .call_params(CallParameters::default().with_amount(fee))?
+            .append_variable_outputs(1);
+        // the call's base asset inputs and change belong to `fee_payer`
+        let mut tb = call_handler.transaction_builder().await?;
+        fee_payer.add_witnesses(&mut tb)?;
+        self.wallet.add_witnesses(&mut tb)?;
+        fee_payer.adjust_for_fee(&mut tb, fee).await?;
+
+        let provider = self.wallet.try_provider()?;
+        let tx = tb.build(provider).await?;
+        let tx_id = tx.id(provider.chain_id());
+        let tx_status = provider.send_transaction_and_await_commit(tx).await?;
+        let mut response = call_handler.get_response_from(tx_status)?;
+        response.tx_id = Some(tx_id);
+
+        EventCallResponse::from_response(response)
+    }
+
+    /// Updates the price feeds if any stored publish time is older than its entry in
+    /// `publish_times` (TAI64), paying `fee`. The whole `fee` is refunded when no update is
+    /// necessary; see [crate::events::refunded_fee].
+    pub async fn update_price_feeds_if_necessary(
+        &self,
+        fee: u64,
+        price_feed_ids: &[Bits256],
+        publish_times: &[u64],
+        update_data: &[Bytes],
+    ) -> Result<FuelCallResponse<()>, Error> {
+        self.instance
+            .methods()
+            .update_price_feeds_if_necessary(
+                price_feed_ids.to_vec(),
+                publish_times.to_vec(),
+                update_data.to_vec(),
+            )
+            .call_params(CallParameters::default().with_amount(fee))?
+            .append_variable_outputs(1)
+            .call()
+            .await
+    }
+
+    pub async fn valid_time_period(&self) -> Result<FuelCallResponse<u64>, Error> {
+        self.instance.methods().valid_time_period().simulate().await
+    }
+
+    // PythInit
+
     pub async fn constructor(
         &self,
         valid_time_period_seconds: u64,
         wormhole_guardian_set_upgrade: Bytes,
-    ) -> Result<FuelCallResponse<()>, Error> {
-        self.instance
+    ) -> Result<EventCallResponse<ConstructedEvent>, Error> {
+        self.constructor_with_data_sources(
+            default_data_sources(),
+            default_governance_data_source(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            valid_time_period_seconds,
+            wormhole_guardian_set_upgrade,
+        )
+        .await
+    }
+
+    /// Calls the `constructor` with the data sources and parameters of `network`.
+    pub async fn constructor_with_network(
+        &self,
+        network: &NetworkConfig,
+    ) -> Result<EventCallResponse<ConstructedEvent>, Error> {
+        self.constructor_with_data_sources(
+            network.data_sources.data_sources(),
+            network.governance_data_source.data_source(),
+            network.single_update_fee,
+            network.valid_time_period_seconds,
+            network.guardian_set_upgrade_vaa.clone(),
+        )
+        .await
+    }
+
+    /// Calls the `constructor` with every parameter, where [Pyth::constructor] uses the default
+    /// data sources, governance data source and single update fee.
+    pub async fn constructor_with_data_sources(
+        &self,
+        data_sources: Vec<DataSource>,
+        governance_data_source: DataSource,
+        single_update_fee: u64,
+        valid_time_period_seconds: u64,
+        wormhole_guardian_set_upgrade: Bytes,
+    ) -> Result<EventCallResponse<ConstructedEvent>, Error> {
+        let response = self
+            .instance
             .methods()
             .constructor(
-                default_data_sources(),
-                DEFAULT_SINGLE_UPDATE_FEE,
+                data_sources,
+                governance_data_source,
+                single_update_fee,
                 valid_time_period_seconds,
                 wormhole_guardian_set_upgrade,
             )
             .with_tx_policies(TxPolicies::default().with_gas_price(1))
             .call()
+            .await?;
+
+        EventCallResponse::from_response(response)
+    }
+
+    // PythGovernance
+
+    pub async fn governance_data_source(&self) -> Result<FuelCallResponse<DataSource>, Error> {
+        self.instance
+            .methods()
+            .governance_data_source()
+            .simulate()
             .await
     }
 
-    pub async fn deploy(wallet: WalletUnlocked) -> Result<Self, Error> {
-        let mut rng = rand::thread_rng();
-        let salt = rng.gen::<[u8; 32]>();
-        let configurables = PythOracleContractConfigurables::default()
-            .with_DEPLOYER(Identity::Address(Address::from(wallet.address())));
-        let config = LoadConfiguration::default().with_configurables(configurables);
-
-        let id = Contract::load_from(
-            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(PYTH_CONTRACT_BINARY_PATH),
-            config,
-        )?;
-        let deployed_contract = id
-            .with_salt(salt)
-            .deploy(&wallet, TxPolicies::default().with_gas_price(1))
+    pub async fn last_executed_governance_sequence(&self) -> Result<FuelCallResponse<u64>, Error> {
+        self.instance
+            .methods()
+            .last_executed_governance_sequence()
+            .simulate()
+            .await
+    }
+
+    /// Executes a governance VAA, see [crate::governance]. Clears the cached single update fee,
+    /// which the instruction may set.
+    pub async fn execute_governance_instruction(
+        &self,
+        vm: Bytes,
+    ) -> Result<FuelCallResponse<()>, Error> {
+        let response = self
+            .instance
+            .methods()
+            .execute_governance_instruction(vm)
+            // the output of a `WithdrawFees` transfer
+            .append_variable_outputs(1)
+            .call()
             .await?;
+        self.single_update_fee_cache.lock().unwrap().take();
 
-        Ok(Self {
-            instance: PythOracleContract::new(deployed_contract, wallet.clone()),
-            wallet,
-        })
+        Ok(response)
+    }
+
+    /// Executes a governance VAA carrying a `WithdrawFees` instruction.
+    pub async fn withdraw_fees(
+        &self,
+        vm: Bytes,
+    ) -> Result<EventCallResponse<FeesWithdrawnEvent>, Error> {
+        let response = self.execute_governance_instruction(vm).await?;
+
+        EventCallResponse::from_response(response)
     }
 
+    // PythInfo
+
+    pub async fn fee_balance(&self) -> Result<FuelCallResponse<u64>, Error> {
+        self.instance.methods().fee_balance().simulate().await
+    }
+
+    pub async fn latest_publish_time(
+        &self,
+        price_feed_id: Bits256,
+    ) -> Result<FuelCallResponse<u64>, Error> {
+        self.instance
+            .methods()
+            .latest_publish_time(price_feed_id)
+            .simulate()
+            .await
+    }
+
+    pub async fn price_feed_exists(
+        &self,
+        price_feed_id: Bits256,
+    ) -> Result<FuelCallResponse<bool>, Error> {
+        self.instance
+            .methods()
+            .price_feed_exists(price_feed_id)
+            .simulate()
+            .await
+    }
+
+    pub async fn price_feed_unsafe(
+        &self,
+        price_feed_id: Bits256,
+    ) -> Result<FuelCallResponse<PriceFeed>, Error> {
+        self.instance
+            .methods()
+            .price_feed_unsafe(price_feed_id)
+            .simulate()
+            .await
+    }
+
+    pub async fn single_update_fee(&self) -> Result<FuelCallResponse<u64>, Error> {
+        self.instance.methods().single_update_fee().simulate().await
+    }
+
+    /// The contract's `single_update_fee`, read on first use. The contract sets it in its
+    /// `constructor` and in `SetFee` governance instructions, so it should not be read before the
+    /// contract is constructed, and is stale once another client executes a `SetFee`.
+    pub async fn cached_single_update_fee(&self) -> Result<u64, Error> {
+        if let Some(single_update_fee) = *self.single_update_fee_cache.lock().unwrap() {
+            return Ok(single_update_fee);
+        }
+        let single_update_fee = self.single_update_fee().await?.value;
+
+        Ok(*self
+            .single_update_fee_cache
+            .lock()
+            .unwrap()
+            .get_or_insert(single_update_fee))
+    }
+
+    pub async fn valid_data_source(
+        &self,
+        data_source: &DataSource,
+    ) -> Result<FuelCallResponse<bool>, Error> {
+        self.instance
+            .methods()
+            .valid_data_source(data_source.clone())
+            .simulate()
+            .await
+    }
+
+    pub async fn valid_data_sources(&self) -> Result<FuelCallResponse<Vec<DataSource>>, Error> {
+        self.instance
+            .methods()
+            .valid_data_sources()
+            .simulate()
+            .await
+    }
+
+    // WormholeGuardians
+
     pub async fn current_guardian_set_index(&self) -> Result<FuelCallResponse<u32>, Error> {
         self.instance
             .methods()
@@ -164,6 +579,109 @@ impl Pyth {
             .simulate()
             .await
     }
+
+    pub async fn current_wormhole_provider(
+        &self,
+    ) -> Result<FuelCallResponse<WormholeProvider>, Error> {
+        self.instance
+            .methods()
+            .current_wormhole_provider()
+            .simulate()
+            .await
+    }
+
+    pub async fn governance_action_is_consumed(
+        &self,
+        governance_action_hash: Bits256,
+    ) -> Result<FuelCallResponse<bool>, Error> {
+        self.instance
+            .methods()
+            .governance_action_is_consumed(governance_action_hash)
+            .simulate()
+            .await
+    }
+
+    pub async fn guardian_set(&self, index: u32) -> Result<FuelCallResponse<GuardianSet>, Error> {
+        self.instance.methods().guardian_set(index).simulate().await
+    }
+
+    pub async fn submit_new_guardian_set(
+        &self,
+        vm: Bytes,
+    ) -> Result<EventCallResponse<NewGuardianSetEvent>, Error> {
+        let response = self
+            .instance
+            .methods()
+            .submit_new_guardian_set(vm)
+            .call()
+            .await?;
+
+        EventCallResponse::from_response(response)
+    }
+
+    // SRC5
+
+    pub async fn owner(&self) -> Result<FuelCallResponse<State>, Error> {
+        self.instance.methods().owner().simulate().await
+    }
+}
+
+impl Pyth {
+    /// Connects to the Pyth contract deployed at `contract_id` through an unfunded random wallet,
+    /// for the read methods, which only simulate calls.
+    ///
+    /// The returned client still has the write methods, such as [Pyth::update_price_feeds] and
+    /// [Pyth::execute_governance_instruction], but the wallet owns no coins to pay for their
+    /// transactions, so they fail at runtime. Use [Pyth::connect] with a funded wallet to send
+    /// transactions.
+    pub async fn connect_readonly(
+        contract_id: ContractId,
+        provider: Provider,
+    ) -> Result<Self, PythSdkError> {
+        Self::connect(contract_id, WalletUnlocked::new_random(Some(provider))).await
+    }
+
+    /// The id of the contract `deployer` would deploy with `salt`, the `DEPLOYER` configurable
+    /// being part of the bytecode.
+    ///
+    /// The id commits to the root of the bytecode, so a deployed contract at this id runs the local
+    /// `pyth-contract.bin`.
+    pub fn deployment_contract_id(deployer: Address, salt: [u8; 32]) -> Result<ContractId, Error> {
+        Ok(load_contract(deployer, salt)?.contract.contract_id())
+    }
+
+    /// The root of the bytecode `deployer` would deploy, which does not depend on the salt.
+    pub fn deployment_code_root(deployer: Address) -> Result<Bytes32, Error> {
+        Ok(load_contract(deployer, [0; 32])?.contract.code_root())
+    }
+}
+
+/// The contract binary with the `DEPLOYER` configurable set, loaded like [Contract::load_from]
+/// does but keeping the binary and storage slots for the create transaction.
+struct LoadedContract {
+    contract: Contract,
+    binary: Vec<u8>,
+    storage_slots: Vec<StorageSlot>,
+}
+
+fn load_contract(deployer: Address, salt: [u8; 32]) -> Result<LoadedContract, Error> {
+    let binary_path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(PYTH_CONTRACT_BINARY_PATH);
+    let mut binary = std::fs::read(&binary_path)?;
+    Configurables::from(
+        PythOracleContractConfigurables::default().with_DEPLOYER(Identity::Address(deployer)),
+    )
+    .update_constants_in(&mut binary);
+
+    let storage_slots: Vec<StorageSlot> = StorageConfiguration::default()
+        .add_slot_overrides_from_file(binary_path.with_file_name(PYTH_CONTRACT_STORAGE_SLOTS_FILE))?
+        .into_slots()
+        .collect();
+
+    Ok(LoadedContract {
+        contract: Contract::new(binary.clone(), salt.into(), storage_slots.clone()),
+        binary,
+        storage_slots,
+    })
 }
 
 pub fn guardian_set_upgrade_3_vaa() -> Bytes {
@@ -174,60 +692,18 @@ pub fn guardian_set_upgrade_4_vaa() -> Bytes {
 }
 
 pub fn default_price_feed_ids() -> Vec<Bits256> {
-    vec![
-        Bits256(
-            hex::decode(ETH_USD_PRICE_FEED_ID)
-                .unwrap()
-                .try_into()
-                .unwrap(),
-        ),
-        Bits256(
-            hex::decode(USDC_USD_PRICE_FEED_ID)
-                .unwrap()
-                .try_into()
-                .unwrap(),
-        ),
-    ]
+    vec![ETH_USD_PRICE_FEED_ID, USDC_USD_PRICE_FEED_ID]
 }
 
-// data sources from Pyth EVM deployment docs:
-// https://github.com/pyth-network/pyth-crosschain/blob/2008da7a451231489d9866d7ceae3799c07e1fb5/contract_manager/src/base.ts#L116
+/// The governance data source of the mainnet preset, Pyth mainnet governance, which is also the
+/// default of network files.
+pub fn default_governance_data_source() -> DataSource {
+    NetworkConfig::mainnet()
+        .governance_data_source
+        .data_source()
+}
+
+/// The data sources of the testnet preset, Pyth stable then beta.
 pub fn default_data_sources() -> Vec<DataSource> {
-    vec![
-        DataSource {
-            chain_id: 1,
-            emitter_address: Bits256::from_hex_str(
-                "6bb14509a612f01fbbc4cffeebd4bbfb492a86df717ebe92eb6df432a3f00a25",
-            )
-            .unwrap(),
-        },
-        DataSource {
-            chain_id: 26,
-            emitter_address: Bits256::from_hex_str(
-                "f8cd23c2ab91237730770bbea08d61005cdda0984348f3f6eecb559638c0bba0",
-            )
-            .unwrap(),
-        },
-        DataSource {
-            chain_id: 26,
-            emitter_address: Bits256::from_hex_str(
-                "e101faedac5851e32b9b23b5f9411a8c2bac4aae3ed4dd7b811dd1a72ea4aa71",
-            )
-            .unwrap(),
-        },
-        DataSource {
-            chain_id: 1,
-            emitter_address: Bits256::from_hex_str(
-                "f346195ac02f37d60d4db8ffa6ef74cb1be3550047543a4a9ee9acf4d78697b0",
-            )
-            .unwrap(),
-        },
-        DataSource {
-            chain_id: 26,
-            emitter_address: Bits256::from_hex_str(
-                "a27839d641b07743c0cb5f68c51f8cd31d2c0762bec00dc6fcd25433ef1ab5b6",
-            )
-            .unwrap(),
-        },
-    ]
+    NetworkConfig::testnet().data_sources.data_sources()
 }
@@ -0,0 +1,23 @@
+use crate::{decode::DecodeError, fee::FeeError, hermes::HermesError};
+use fuels::types::{errors::Error, ContractId};
+use thiserror::Error;
+
+/// Error of `Pyth` methods that do more than a single contract call, such as fetching from Hermes
+/// or connecting to an existing deployment.
+#[derive(Debug, Error)]
+pub enum PythSdkError {
+    #[error(transparent)]
+    Hermes(#[from] HermesError),
+    #[error(transparent)]
+    Fuels(#[from] Error),
+    #[error(transparent)]
+    Decode(#[from] DecodeError),
+    #[error(transparent)]
+    Fee(#[from] FeeError),
+    #[error("contract {contract_id} does not expose the Pyth ABI")]
+    NotPythContract {
+        contract_id: ContractId,
+        #[source]
+        source: Error,
+    },
+}
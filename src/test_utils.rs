@@ -0,0 +1,299 @@
+use crate::{
+    accumulator::{
+        AccumulatorUpdate, MerklePriceUpdate, PriceFeedMessage, WormholeMerkleRoot, MAJOR_VERSION,
+    },
+    batch_attestation::{BatchAttestationUpdate, PriceAttestation, PriceStatus},
+    governance::GovernanceInstruction,
+    pyth_utils::{default_data_sources, DataSource, GuardianSet},
+    wormhole::{address, guardian_key, GuardianSetUpgrade, GuardianSignature, Vaa, VAA_VERSION},
+};
+use fuels::types::{Bits256, Bytes};
+use k256::ecdsa::SigningKey;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Chain id of the Wormhole governance emitter.
+pub const GOVERNANCE_CHAIN_ID: u16 = 1;
+/// Address of the Wormhole governance emitter.
+pub const GOVERNANCE_EMITTER_ADDRESS: Bits256 = Bits256([
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4,
+]);
+
+/// Secret key of the guardian of Wormhole's devnet, the only guardian of the `local` network
+/// preset.
+pub const LOCAL_GUARDIAN_KEY: &str =
+    "cfb12303a19cde580bb4dd771639b0d26bc68353645571a8cff516ab2ee113a0";
+/// Index of the guardian set installed by the `local` network preset.
+pub const LOCAL_GUARDIAN_SET_INDEX: u32 = 1;
+
+/// The fields of a synthetic price feed update:
+/// `(id, price, conf, expo, publish_time, ema_price, ema_conf)`.
+pub type PriceFeedUpdate = (Bits256, i64, u64, i32, i64, i64, u64);
+
+/// A guardian set backed by local secp256k1 keys, signing VAAs the contract accepts once the set
+/// is its current guardian set.
+#[derive(Clone, Debug)]
+pub struct LocalGuardianSet {
+    index: u32,
+    keys: Vec<SigningKey>,
+}
+
+impl LocalGuardianSet {
+    pub fn new(index: u32, keys: Vec<SigningKey>) -> Self {
+        Self { index, keys }
+    }
+
+    pub fn random(index: u32, size: usize) -> Self {
+        let mut rng = rand::thread_rng();
+
+        Self::new(
+            index,
+            (0..size).map(|_| SigningKey::random(&mut rng)).collect(),
+        )
+    }
+
+    /// The guardian set installed by the `local` network preset, whose key is public, signing the
+    /// updates and governance VAAs of the preset's emitters.
+    pub fn local() -> Self {
+        let key = hex::decode(LOCAL_GUARDIAN_KEY).expect("the local guardian key is hex");
+
+        Self::new(
+            LOCAL_GUARDIAN_SET_INDEX,
+            vec![SigningKey::from_slice(&key).expect("the local guardian key is a secret key")],
+        )
+    }
+
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    pub fn addresses(&self) -> Vec<[u8; 20]> {
+        self.keys
+            .iter()
+            .map(|key| address(key.verifying_key()))
+            .collect()
+    }
+
+    /// The guardian set as the contract stores it.
+    pub fn guardian_set(&self) -> GuardianSet {
+        GuardianSet {
+            expiration_time: 0,
+            keys: self.addresses().into_iter().map(guardian_key).collect(),
+        }
+    }
+
+    /// Sets the VAA's guardian set index and signs it with every guardian.
+    pub fn sign(&self, vaa: &mut Vaa) {
+        let guardians: Vec<u8> = (0..self.keys.len() as u8).collect();
+
+        self.sign_with(vaa, &guardians);
+    }
+
+    /// Sets the VAA's guardian set index and signs it with the guardians at `guardians`, in the
+    /// given order, which allows building VAAs without quorum or with unordered signatures.
+    pub fn sign_with(&self, vaa: &mut Vaa, guardians: &[u8]) {
+        vaa.guardian_set_index = self.index;
+
+        let digest = vaa.digest();
+        vaa.signatures = guardians
+            .iter()
+            .map(|index| {
+                let (signature, recovery_id) = self.keys[usize::from(*index)]
+                    .sign_prehash_recoverable(&digest)
+                    .unwrap();
+
+                let mut encoded = [0u8; 65];
+                encoded[..64].copy_from_slice(&signature.to_bytes());
+                encoded[64] = recovery_id.to_byte();
+
+                GuardianSignature {
+                    index: *index,
+                    signature: encoded,
+                }
+            })
+            .collect();
+    }
+
+    /// A VAA from `emitter` carrying `payload`, signed by every guardian.
+    pub fn signed_vaa(&self, emitter: &DataSource, sequence: u64, payload: Vec<u8>) -> Vaa {
+        let mut vaa = Vaa {
+            version: VAA_VERSION,
+            guardian_set_index: self.index,
+            signatures: vec![],
+            timestamp: now() as u32,
+            nonce: 0,
+            emitter_chain_id: emitter.chain_id,
+            emitter_address: emitter.emitter_address,
+            sequence,
+            consistency_level: 1,
+            payload,
+        };
+        self.sign(&mut vaa);
+
+        vaa
+    }
+
+    /// A governance VAA upgrading to `new_guardian_set`, signed by this set.
+    ///
+    /// The `constructor` does not verify the signatures of its upgrade VAA, so any set can sign the
+    /// one that installs the first local guardian set.
+    pub fn guardian_set_upgrade_vaa(&self, new_guardian_set: &LocalGuardianSet) -> Bytes {
+        let upgrade = GuardianSetUpgrade {
+            chain: 0,
+            new_guardian_set_index: new_guardian_set.index,
+            keys: new_guardian_set.addresses(),
+        };
+        let governance_emitter = DataSource {
+            chain_id: GOVERNANCE_CHAIN_ID,
+            emitter_address: GOVERNANCE_EMITTER_ADDRESS,
+        };
+
+        Bytes(
+            self.signed_vaa(
+                &governance_emitter,
+                new_guardian_set.index.into(),
+                upgrade.encode(),
+            )
+            .encode(),
+        )
+    }
+
+    /// A governance VAA from `governance_data_source` carrying `instruction`, signed by every
+    /// guardian.
+    pub fn governance_vaa(
+        &self,
+        governance_data_source: &DataSource,
+        sequence: u64,
+        instruction: &GovernanceInstruction,
+    ) -> Bytes {
+        Bytes(
+            self.signed_vaa(governance_data_source, sequence, instruction.encode())
+                .encode(),
+        )
+    }
+
+    /// An accumulator update from `emitter` for `messages`, signed by every guardian.
+    pub fn accumulator_update(&self, emitter: &DataSource, messages: &[PriceFeedMessage]) -> Bytes {
+        let (digest, updates) = MerklePriceUpdate::tree(messages);
+        let merkle_root = WormholeMerkleRoot {
+            slot: 0,
+            ring_size: 0,
+            digest,
+        };
+
+        let update = AccumulatorUpdate {
+            major_version: MAJOR_VERSION,
+            minor_version: 0,
+            trailing_header: vec![],
+            vaa: self.signed_vaa(emitter, 0, merkle_root.encode()),
+            merkle_root,
+            updates,
+        };
+
+        Bytes(update.encode())
+    }
+
+    /// A batch attestation update from `emitter` for `attestations`, signed by every guardian.
+    pub fn batch_update(&self, emitter: &DataSource, attestations: &[PriceAttestation]) -> Bytes {
+        let payload = BatchAttestationUpdate::encode_payload(attestations);
+
+        Bytes(self.signed_vaa(emitter, 0, payload).encode())
+    }
+}
+
+/// Builds accumulator update data for synthetic price feeds, signed by a local guardian set.
+#[derive(Clone, Debug)]
+pub struct UpdateDataBuilder {
+    guardians: LocalGuardianSet,
+    emitter: DataSource,
+    messages: Vec<PriceFeedMessage>,
+}
+
+impl UpdateDataBuilder {
+    /// A builder signing with `guardians` as the first of the default data sources.
+    pub fn new(guardians: LocalGuardianSet) -> Self {
+        Self {
+            guardians,
+            emitter: default_data_sources()[0].clone(),
+            messages: vec![],
+        }
+    }
+
+    pub fn with_emitter(mut self, emitter: DataSource) -> Self {
+        self.emitter = emitter;
+        self
+    }
+
+    /// Adds a price feed, whose previous publish time is its publish time.
+    pub fn with_price_feed(mut self, price_feed: PriceFeedUpdate) -> Self {
+        let (id, price, conf, exponent, publish_time, ema_price, ema_conf) = price_feed;
+
+        self.messages.push(PriceFeedMessage {
+            id,
+            price,
+            conf,
+            exponent,
+            publish_time,
+            prev_publish_time: publish_time,
+            ema_price,
+            ema_conf,
+        });
+        self
+    }
+
+    pub fn with_price_feeds(self, price_feeds: impl IntoIterator<Item = PriceFeedUpdate>) -> Self {
+        price_feeds.into_iter().fold(self, Self::with_price_feed)
+    }
+
+    /// Adds a price feed message as is.
+    pub fn with_message(mut self, message: PriceFeedMessage) -> Self {
+        self.messages.push(message);
+        self
+    }
+
+    pub fn guardians(&self) -> &LocalGuardianSet {
+        &self.guardians
+    }
+
+    pub fn emitter(&self) -> &DataSource {
+        &self.emitter
+    }
+
+    pub fn messages(&self) -> &[PriceFeedMessage] {
+        &self.messages
+    }
+
+    /// The accumulator update of every price feed, in the order they were added.
+    pub fn build(&self) -> Bytes {
+        self.guardians
+            .accumulator_update(&self.emitter, &self.messages)
+    }
+}
+
+/// A trading attestation of `message`.
+pub fn trading_attestation(message: &PriceFeedMessage) -> PriceAttestation {
+    PriceAttestation {
+        product_id: message.id,
+        price_id: message.id,
+        price: message.price,
+        conf: message.conf,
+        exponent: message.exponent,
+        ema_price: message.ema_price,
+        ema_conf: message.ema_conf,
+        status: PriceStatus::Trading,
+        num_publishers: 1,
+        max_num_publishers: 1,
+        attestation_time: message.publish_time,
+        publish_time: message.publish_time,
+        prev_publish_time: message.prev_publish_time,
+        prev_price: message.price,
+        prev_conf: message.conf,
+    }
+}
+
+/// The current UNIX time in seconds.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
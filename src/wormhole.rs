@@ -0,0 +1,278 @@
+use crate::{
+    decode::{DecodeError, Reader},
+    pyth_utils::GuardianSet,
+};
+use fuels::types::Bits256;
+use k256::ecdsa::{RecoveryId, Signature, VerifyingKey};
+use sha3::{Digest, Keccak256};
+use thiserror::Error;
+
+pub const VAA_VERSION: u8 = 1;
+/// The `Core` governance module, right aligned.
+pub const UPGRADE_MODULE: [u8; 32] = {
+    let mut module = [0u8; 32];
+    module[28] = b'C';
+    module[29] = b'o';
+    module[30] = b'r';
+    module[31] = b'e';
+    module
+};
+
+const GUARDIAN_SET_UPGRADE_ACTION: u8 = 2;
+
+/// A guardian's signature over the VAA body.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GuardianSignature {
+    /// Index of the guardian in the guardian set.
+    pub index: u8,
+    /// `r`, `s` and the recovery id `v`.
+    pub signature: [u8; 65],
+}
+
+/// A Wormhole verified action approval.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vaa {
+    pub version: u8,
+    pub guardian_set_index: u32,
+    pub signatures: Vec<GuardianSignature>,
+    pub timestamp: u32,
+    pub nonce: u32,
+    pub emitter_chain_id: u16,
+    pub emitter_address: Bits256,
+    pub sequence: u64,
+    pub consistency_level: u8,
+    pub payload: Vec<u8>,
+}
+
+/// The payload of a guardian set upgrade governance VAA.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GuardianSetUpgrade {
+    pub chain: u16,
+    pub new_guardian_set_index: u32,
+    pub keys: Vec<[u8; 20]>,
+}
+
+/// Reasons the contract rejects a VAA's signatures, named after the `WormholeError` it reverts with.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum VerificationError {
+    #[error("VAA version {0} is incompatible")]
+    VMVersionIncompatible(u8),
+    #[error("the guardian set has no keys")]
+    InvalidGuardianSetKeysLength,
+    #[error("signature index {index} does not follow index {last_index}")]
+    SignatureIndicesNotAscending { index: u8, last_index: u8 },
+    #[error("the guardian set has no key at index {0}")]
+    GuardianSetKeyIrretrievable(u8),
+    #[error("signature of guardian {0} is invalid")]
+    SignatureInvalid(u8),
+    #[error("{signatures} signatures do not reach the quorum of {quorum}")]
+    NoQuorum { signatures: usize, quorum: usize },
+}
+
+impl Vaa {
+    pub fn parse(data: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = Reader::new(data);
+
+        let version = reader.u8()?;
+        let guardian_set_index = reader.u32()?;
+        let number_of_signatures = reader.u8()?;
+        let signatures = (0..number_of_signatures)
+            .map(|_| {
+                Ok(GuardianSignature {
+                    index: reader.u8()?,
+                    signature: reader.array()?,
+                })
+            })
+            .collect::<Result<Vec<GuardianSignature>, DecodeError>>()?;
+
+        Ok(Self {
+            version,
+            guardian_set_index,
+            signatures,
+            timestamp: reader.u32()?,
+            nonce: reader.u32()?,
+            emitter_chain_id: reader.u16()?,
+            emitter_address: Bits256(reader.array()?),
+            sequence: reader.u64()?,
+            consistency_level: reader.u8()?,
+            payload: reader.rest().to_vec(),
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut data = vec![self.version];
+        data.extend(self.guardian_set_index.to_be_bytes());
+        data.push(self.signatures.len() as u8);
+        for signature in &self.signatures {
+            data.push(signature.index);
+            data.extend(signature.signature);
+        }
+        data.extend(self.body());
+
+        data
+    }
+
+    /// The signed part of the VAA.
+    pub fn body(&self) -> Vec<u8> {
+        let mut body = Vec::with_capacity(51 + self.payload.len());
+        body.extend(self.timestamp.to_be_bytes());
+        body.extend(self.nonce.to_be_bytes());
+        body.extend(self.emitter_chain_id.to_be_bytes());
+        body.extend(self.emitter_address.0);
+        body.extend(self.sequence.to_be_bytes());
+        body.push(self.consistency_level);
+        body.extend(&self.payload);
+
+        body
+    }
+
+    /// The digest the guardians sign, the double keccak256 of the body.
+    pub fn digest(&self) -> [u8; 32] {
+        keccak256(&keccak256(&self.body()))
+    }
+
+    /// Recovers the address of every signer, in signature order.
+    pub fn signers(&self) -> Result<Vec<[u8; 20]>, VerificationError> {
+        let digest = self.digest();
+
+        self.signatures
+            .iter()
+            .map(|signature| signature.recover(&digest))
+            .collect()
+    }
+
+    /// Verifies the signatures against `guardian_set` by the same rules as the contract:
+    /// signature indices must be strictly ascending, every signature must recover to the key at its
+    /// index, and more than two thirds of the guardians must have signed.
+    ///
+    /// Whether `guardian_set` is the contract's current, unexpired set is left to the caller.
+    pub fn verify(&self, guardian_set: &GuardianSet) -> Result<(), VerificationError> {
+        if self.version != VAA_VERSION {
+            return Err(VerificationError::VMVersionIncompatible(self.version));
+        }
+        if guardian_set.keys.is_empty() {
+            return Err(VerificationError::InvalidGuardianSetKeysLength);
+        }
+
+        let digest = self.digest();
+        for (i, signature) in self.signatures.iter().enumerate() {
+            if i > 0 {
+                let last_index = self.signatures[i - 1].index;
+                if signature.index <= last_index {
+                    return Err(VerificationError::SignatureIndicesNotAscending {
+                        index: signature.index,
+                        last_index,
+                    });
+                }
+            }
+
+            let key = guardian_set.keys.get(usize::from(signature.index)).ok_or(
+                VerificationError::GuardianSetKeyIrretrievable(signature.index),
+            )?;
+            let signer = signature.recover(&digest)?;
+            if *key != guardian_key(signer) {
+                return Err(VerificationError::SignatureInvalid(signature.index));
+            }
+        }
+
+        let quorum = quorum(guardian_set.keys.len());
+        if self.signatures.len() < quorum {
+            return Err(VerificationError::NoQuorum {
+                signatures: self.signatures.len(),
+                quorum,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+impl GuardianSignature {
+    /// Recovers the Ethereum style address of the guardian that signed `digest`.
+    pub fn recover(&self, digest: &[u8; 32]) -> Result<[u8; 20], VerificationError> {
+        let invalid = || VerificationError::SignatureInvalid(self.index);
+
+        let signature = Signature::from_slice(&self.signature[..64]).map_err(|_| invalid())?;
+        let recovery_id = RecoveryId::from_byte(self.signature[64]).ok_or_else(invalid)?;
+        let key = VerifyingKey::recover_from_prehash(digest, &signature, recovery_id)
+            .map_err(|_| invalid())?;
+
+        Ok(address(&key))
+    }
+}
+
+impl GuardianSetUpgrade {
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        let mut reader = Reader::new(payload);
+
+        let module = reader.array::<32>()?;
+        if module != UPGRADE_MODULE {
+            return Err(DecodeError::InvalidModule);
+        }
+        let action = reader.u8()?;
+        if action != GUARDIAN_SET_UPGRADE_ACTION {
+            return Err(DecodeError::InvalidGovernanceAction(action));
+        }
+        let chain = reader.u16()?;
+        let new_guardian_set_index = reader.u32()?;
+        let number_of_keys = reader.u8()?;
+        let keys = (0..number_of_keys)
+            .map(|_| reader.array())
+            .collect::<Result<Vec<[u8; 20]>, DecodeError>>()?;
+        reader.finish()?;
+
+        Ok(Self {
+            chain,
+            new_guardian_set_index,
+            keys,
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut payload = UPGRADE_MODULE.to_vec();
+        payload.push(GUARDIAN_SET_UPGRADE_ACTION);
+        payload.extend(self.chain.to_be_bytes());
+        payload.extend(self.new_guardian_set_index.to_be_bytes());
+        payload.push(self.keys.len() as u8);
+        for key in &self.keys {
+            payload.extend(key);
+        }
+
+        payload
+    }
+
+    /// The new guardian set as the contract stores it.
+    pub fn guardian_set(&self) -> GuardianSet {
+        GuardianSet {
+            expiration_time: 0,
+            keys: self.keys.iter().copied().map(guardian_key).collect(),
+        }
+    }
+}
+
+/// The number of signatures needed to verify a VAA signed by a guardian set of `number_of_keys`.
+pub fn quorum(number_of_keys: usize) -> usize {
+    // the contract's fixed point formula, equal to floor(2n / 3) + 1
+    ((number_of_keys * 10 / 3) * 2) / 10 + 1
+}
+
+/// A guardian address as the contract stores it, right aligned in a b256.
+pub fn guardian_key(address: [u8; 20]) -> Bits256 {
+    let mut key = [0u8; 32];
+    key[12..].copy_from_slice(&address);
+
+    Bits256(key)
+}
+
+/// The Ethereum style address of a public key, the last 20 bytes of its keccak256 hash.
+pub(crate) fn address(key: &VerifyingKey) -> [u8; 20] {
+    let encoded = key.to_encoded_point(false);
+
+    keccak256(&encoded.as_bytes()[1..])[12..]
+        .try_into()
+        .unwrap()
+}
+
+pub(crate) fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
@@ -0,0 +1,132 @@
+use pyth_sdk::{
+    accumulator::AccumulatorUpdate,
+    constants::{TEST_ACCUMULATOR_ETH_USD_PRICE_FEED, TEST_ACCUMULATOR_USDC_USD_PRICE_FEED},
+    decode::DecodeError,
+    pyth_utils::{
+        default_data_sources, test_accumulator_update_data_bytes, test_batch_update_data_bytes,
+    },
+};
+
+fn test_accumulator_update_data() -> Vec<u8> {
+    test_accumulator_update_data_bytes().remove(0).0
+}
+
+mod success {
+
+    use super::*;
+
+    #[test]
+    fn parses_header_and_vaa() {
+        let update = AccumulatorUpdate::parse(&test_accumulator_update_data()).unwrap();
+
+        assert_eq!(update.major_version, 1);
+        assert_eq!(update.minor_version, 0);
+        assert!(update.trailing_header.is_empty());
+        assert_eq!(update.vaa.guardian_set_index, 3);
+        assert_eq!(update.vaa.signatures.len(), 13);
+        assert_eq!(update.vaa.timestamp, 1710723231);
+        assert_eq!(update.vaa.sequence, 45593574);
+        assert_eq!(
+            (update.vaa.emitter_chain_id, update.vaa.emitter_address),
+            (
+                default_data_sources()[2].chain_id,
+                default_data_sources()[2].emitter_address
+            )
+        );
+    }
+
+    #[test]
+    fn parses_wormhole_merkle_root() {
+        let update = AccumulatorUpdate::parse(&test_accumulator_update_data()).unwrap();
+
+        assert_eq!(update.merkle_root.slot, 130081371);
+        assert_eq!(update.merkle_root.ring_size, 10000);
+        assert_eq!(
+            hex::encode(update.merkle_root.digest),
+            "16cf8c2f2b7d5930db82bbc329e98403bec6b29b"
+        );
+    }
+
+    #[test]
+    fn parses_price_feed_messages() {
+        let update = AccumulatorUpdate::parse(&test_accumulator_update_data()).unwrap();
+
+        assert_eq!(
+            update.price_feeds(),
+            vec![
+                TEST_ACCUMULATOR_ETH_USD_PRICE_FEED,
+                TEST_ACCUMULATOR_USDC_USD_PRICE_FEED
+            ]
+        );
+        assert_eq!(update.updates[0].message.exponent, -8);
+        assert!(update
+            .updates
+            .iter()
+            .all(|update| update.message.prev_publish_time < update.message.publish_time));
+    }
+
+    #[test]
+    fn merkle_paths_resolve_to_signed_digest() {
+        let update = AccumulatorUpdate::parse(&test_accumulator_update_data()).unwrap();
+
+        for price_update in &update.updates {
+            assert_eq!(price_update.proof.len(), 10);
+            assert_eq!(price_update.merkle_root(), update.merkle_root.digest);
+        }
+    }
+
+    #[test]
+    fn distinguishes_accumulator_from_batch_updates() {
+        assert!(AccumulatorUpdate::is_accumulator_update(
+            &test_accumulator_update_data()
+        ));
+        assert!(!AccumulatorUpdate::is_accumulator_update(
+            &test_batch_update_data_bytes()[0].0
+        ));
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[test]
+    fn fails_on_batch_update_data() {
+        let error = AccumulatorUpdate::parse(&test_batch_update_data_bytes()[0].0).unwrap_err();
+
+        assert!(matches!(error, DecodeError::InvalidMagic { .. }));
+    }
+
+    #[test]
+    fn fails_on_unsupported_major_version() {
+        let mut data = test_accumulator_update_data();
+        data[4] = 2;
+
+        let error = AccumulatorUpdate::parse(&data).unwrap_err();
+
+        assert_eq!(error, DecodeError::InvalidMajorVersion(2));
+    }
+
+    #[test]
+    fn fails_on_truncated_update_data() {
+        let mut data = test_accumulator_update_data();
+        data.truncate(data.len() - 1);
+
+        let error = AccumulatorUpdate::parse(&data).unwrap_err();
+
+        assert!(matches!(
+            error,
+            DecodeError::UnexpectedEnd { needed: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn fails_on_trailing_bytes() {
+        let mut data = test_accumulator_update_data();
+        data.push(0);
+
+        let error = AccumulatorUpdate::parse(&data).unwrap_err();
+
+        assert_eq!(error, DecodeError::TrailingBytes(1));
+    }
+}
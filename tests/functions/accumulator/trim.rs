@@ -0,0 +1,109 @@
+use crate::utils::{
+    interface::{
+        pyth_core::{update_fee, update_price_feeds},
+        pyth_info::price_feed_exists,
+        pyth_init::constructor,
+    },
+    setup::setup_environment,
+};
+use pyth_sdk::{
+    accumulator::{trim_update_data, AccumulatorUpdate},
+    constants::{DEFAULT_SINGLE_UPDATE_FEE, TEST_EXTENDED_TIME_PERIOD},
+    fee::compute_update_fee,
+    pyth_utils::{
+        default_data_sources, default_price_feed_ids, guardian_set_upgrade_3_vaa,
+        test_accumulator_update_data_bytes, test_batch_update_data_bytes,
+    },
+};
+
+mod success {
+
+    use super::*;
+
+    #[test]
+    fn keeps_only_requested_messages() {
+        let update_data = test_accumulator_update_data_bytes();
+        let price_feed_id = default_price_feed_ids()[1];
+
+        let trimmed = trim_update_data(&update_data, &[price_feed_id]).unwrap();
+        let update = AccumulatorUpdate::parse(&update_data[0].0).unwrap();
+        let trimmed_update = AccumulatorUpdate::parse(&trimmed[0].0).unwrap();
+
+        assert_eq!(trimmed_update.vaa, update.vaa);
+        assert_eq!(trimmed_update.merkle_root, update.merkle_root);
+        assert_eq!(
+            trimmed_update.updates,
+            update
+                .updates
+                .into_iter()
+                .filter(|update| update.message.id == price_feed_id)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            trimmed_update.updates[0].merkle_root(),
+            trimmed_update.merkle_root.digest
+        );
+        assert!(
+            compute_update_fee(&trimmed, DEFAULT_SINGLE_UPDATE_FEE).unwrap()
+                < compute_update_fee(&update_data, DEFAULT_SINGLE_UPDATE_FEE).unwrap()
+        );
+    }
+
+    #[test]
+    fn keeps_update_data_unchanged_for_every_price_feed() {
+        let update_data = test_accumulator_update_data_bytes();
+
+        assert_eq!(
+            trim_update_data(&update_data, &default_price_feed_ids()).unwrap(),
+            update_data
+        );
+    }
+
+    #[test]
+    fn keeps_batch_update_data_unchanged() {
+        let update_data = test_batch_update_data_bytes();
+
+        assert_eq!(
+            trim_update_data(&update_data, &default_price_feed_ids()[..1]).unwrap(),
+            update_data
+        );
+    }
+
+    #[tokio::test]
+    async fn updates_price_feeds_with_trimmed_update() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            TEST_EXTENDED_TIME_PERIOD,
+            guardian_set_upgrade_3_vaa(),
+        )
+        .await;
+
+        let update_data = trim_update_data(
+            &test_accumulator_update_data_bytes(),
+            &default_price_feed_ids()[..1],
+        )
+        .unwrap();
+        let fee = update_fee(&deployer.instance, update_data.clone())
+            .await
+            .value;
+
+        update_price_feeds(&deployer.instance, fee, update_data).await;
+
+        assert_eq!(fee, DEFAULT_SINGLE_UPDATE_FEE);
+        assert_eq!(
+            (
+                price_feed_exists(&deployer.instance, default_price_feed_ids()[0])
+                    .await
+                    .value,
+                price_feed_exists(&deployer.instance, default_price_feed_ids()[1])
+                    .await
+                    .value
+            ),
+            (true, false)
+        );
+    }
+}
@@ -0,0 +1,128 @@
+use pyth_sdk::{
+    batch_attestation::{BatchAttestationUpdate, PriceStatus},
+    constants::{TAI64_DIFFERENCE, TEST_BATCH_ETH_USD_PRICE_FEED, TEST_BATCH_USDC_USD_PRICE_FEED},
+    decode::DecodeError,
+    pyth_utils::{
+        default_data_sources, test_accumulator_update_data_bytes, test_batch_update_data_bytes,
+    },
+};
+
+// offset of the P2WH payload in the test VAAs, which carry 13 signatures
+const PAYLOAD_OFFSET: usize = 6 + 13 * 66 + 51;
+
+fn test_batch_update_data() -> Vec<Vec<u8>> {
+    test_batch_update_data_bytes()
+        .into_iter()
+        .map(|update| update.0)
+        .collect()
+}
+
+mod success {
+
+    use super::*;
+
+    #[test]
+    fn parses_header_and_vaa() {
+        let update = BatchAttestationUpdate::parse(&test_batch_update_data()[0]).unwrap();
+
+        assert_eq!(update.vaa.guardian_set_index, 3);
+        assert_eq!(
+            (update.vaa.emitter_chain_id, update.vaa.emitter_address),
+            (
+                default_data_sources()[1].chain_id,
+                default_data_sources()[1].emitter_address
+            )
+        );
+        assert_eq!(update.major_version, 3);
+        assert_eq!(update.minor_version, 1);
+        assert!(update.trailing_header.is_empty());
+        assert_eq!(update.attestation_size, 157);
+        assert_eq!(update.attestations.len(), 5);
+    }
+
+    #[test]
+    fn parses_fields_ignored_by_the_contract() {
+        let update = BatchAttestationUpdate::parse(&test_batch_update_data()[0]).unwrap();
+        let attestation = update.attestations[2];
+
+        assert_eq!(attestation.price_id, TEST_BATCH_ETH_USD_PRICE_FEED.id);
+        assert_ne!(attestation.product_id, attestation.price_id);
+        assert_eq!(attestation.status, PriceStatus::Trading);
+        assert_eq!(attestation.num_publishers, 28);
+        assert_eq!(attestation.max_num_publishers, 32);
+        assert_eq!(attestation.attestation_time, 1695132706);
+        assert_eq!(attestation.prev_publish_time, 1695132705);
+    }
+
+    #[test]
+    fn converts_attestations_to_stored_price_feeds() {
+        let updates: Vec<BatchAttestationUpdate> = test_batch_update_data()
+            .iter()
+            .map(|data| BatchAttestationUpdate::parse(data).unwrap())
+            .collect();
+
+        assert_eq!(updates[0].price_feeds()[2], TEST_BATCH_ETH_USD_PRICE_FEED);
+        assert_eq!(updates[1].price_feeds()[4], TEST_BATCH_USDC_USD_PRICE_FEED);
+    }
+
+    #[test]
+    fn falls_back_to_previous_price_when_not_trading() {
+        let update = BatchAttestationUpdate::parse(&test_batch_update_data()[0]).unwrap();
+        let attestation = update.attestations[4];
+        let price_feed = attestation.to_price_feed();
+
+        assert_eq!(attestation.status, PriceStatus::Unknown);
+        assert!(attestation.uses_previous_price());
+        assert_eq!(price_feed.price.price, attestation.prev_price as u64);
+        assert_eq!(price_feed.price.confidence, attestation.prev_conf);
+        assert_eq!(
+            price_feed.price.publish_time,
+            attestation.prev_publish_time as u64 + TAI64_DIFFERENCE
+        );
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[test]
+    fn fails_on_accumulator_update_data() {
+        let data = test_accumulator_update_data_bytes().remove(0).0;
+
+        assert!(BatchAttestationUpdate::parse(&data).is_err());
+    }
+
+    #[test]
+    fn fails_on_unsupported_major_version() {
+        let mut data = test_batch_update_data().remove(0);
+        data[PAYLOAD_OFFSET + 5] = 4;
+
+        let error = BatchAttestationUpdate::parse(&data).unwrap_err();
+
+        assert_eq!(error, DecodeError::InvalidMajorVersion(4));
+    }
+
+    #[test]
+    fn fails_on_invalid_payload_id() {
+        let mut data = test_batch_update_data().remove(0);
+        data[PAYLOAD_OFFSET + 10] = 1;
+
+        let error = BatchAttestationUpdate::parse(&data).unwrap_err();
+
+        assert_eq!(error, DecodeError::InvalidPayloadId(1));
+    }
+
+    #[test]
+    fn fails_on_truncated_attestations() {
+        let mut data = test_batch_update_data().remove(0);
+        data.truncate(data.len() - 1);
+
+        let error = BatchAttestationUpdate::parse(&data).unwrap_err();
+
+        assert!(matches!(
+            error,
+            DecodeError::UnexpectedEnd { needed: 1, .. }
+        ));
+    }
+}
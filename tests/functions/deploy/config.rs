@@ -0,0 +1,215 @@
+use pyth_sdk::{
+    constants::{BETA_5_URL, DEFAULT_SINGLE_UPDATE_FEE, DEFAULT_VALID_TIME_PERIOD, LOCAL_URL},
+    deploy::{DeployConfig, DeployError, DEFAULT_MANIFEST_PATH},
+    network::{Network, NetworkConfig, NetworkError},
+    pyth_utils::{default_data_sources, guardian_set_upgrade_4_vaa},
+    test_utils::LocalGuardianSet,
+};
+use std::path::{Path, PathBuf};
+
+const EXAMPLE_CONFIG: &str = include_str!("../../../scripts/deploy.example.toml");
+
+/// Writes `contents` to a file of the temporary directory unique to `name`.
+fn temp_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("pyth_deploy_{}_{name}", std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+
+    path
+}
+
+mod success {
+
+    use super::*;
+
+    #[test]
+    fn parses_example_config() {
+        let config = DeployConfig::from_toml(EXAMPLE_CONFIG).unwrap();
+        let network = config.network_config().unwrap();
+
+        assert_eq!(config.network, Network::Testnet);
+        assert_eq!(network.provider_url, BETA_5_URL);
+        assert_eq!(network.single_update_fee, DEFAULT_SINGLE_UPDATE_FEE);
+        assert_eq!(network.valid_time_period_seconds, DEFAULT_VALID_TIME_PERIOD);
+        assert_eq!(network.data_sources.data_sources(), default_data_sources());
+        assert_eq!(config.salt, None);
+        assert_eq!(config.manifest, Path::new(DEFAULT_MANIFEST_PATH));
+    }
+
+    #[test]
+    fn defaults_to_testnet_preset() {
+        let config = DeployConfig::from_toml("").unwrap();
+
+        assert_eq!(config, DeployConfig::default());
+        assert_eq!(config.network_config().unwrap(), NetworkConfig::testnet());
+        assert_eq!(
+            config.network_config().unwrap().guardian_set_upgrade_vaa,
+            guardian_set_upgrade_4_vaa()
+        );
+    }
+
+    #[test]
+    fn parses_beta_5_alias() {
+        let config = DeployConfig::from_toml("network = \"beta-5\"").unwrap();
+
+        assert_eq!(config.network, Network::Testnet);
+    }
+
+    #[test]
+    fn overrides_network_preset() {
+        let local = DeployConfig::from_toml("network = \"local\"").unwrap();
+        let custom =
+            DeployConfig::from_toml("network = \"local\"\nprovider_url = \"testnet.fuel.network\"")
+                .unwrap();
+
+        assert_eq!(local.network_config().unwrap().provider_url, LOCAL_URL);
+        assert_eq!(
+            custom.network_config().unwrap().provider_url,
+            "testnet.fuel.network"
+        );
+    }
+
+    #[test]
+    fn parses_parameters() {
+        let config = DeployConfig::from_toml(
+            r#"
+            single_update_fee = 5
+            valid_time_period_seconds = 120
+            salt = "0x0000000000000000000000000000000000000000000000000000000000000001"
+            manifest = "beta-5.json"
+
+            [governance_data_source]
+            chain_id = 26
+            emitter_address = "0xf8cd23c2ab91237730770bbea08d61005cdda0984348f3f6eecb559638c0bba0"
+
+            [[data_sources]]
+            chain_id = 2
+            emitter_address = "0x6bb14509a612f01fbbc4cffeebd4bbfb492a86df717ebe92eb6df432a3f00a25"
+            "#,
+        )
+        .unwrap();
+        let network = config.network_config().unwrap();
+        let data_sources = network.data_sources.data_sources();
+
+        assert_eq!(network.single_update_fee, 5);
+        assert_eq!(network.valid_time_period_seconds, 120);
+        assert_eq!(config.salt.unwrap().0[31], 1);
+        assert_eq!(config.manifest, Path::new("beta-5.json"));
+        assert_eq!(data_sources.len(), 1);
+        assert_eq!(data_sources[0].chain_id, 2);
+        assert_eq!(
+            data_sources[0].emitter_address,
+            default_data_sources()[0].emitter_address
+        );
+        assert_eq!(
+            network.governance_data_source.data_source(),
+            default_data_sources()[1]
+        );
+    }
+
+    #[test]
+    fn reads_network_file() {
+        let path = temp_file(
+            "network.toml",
+            "name = \"devnet\"\nprovider_url = \"devnet.fuel.network\"\ndata_sources = []",
+        );
+        let config = DeployConfig {
+            network_file: Some(path),
+            single_update_fee: Some(2),
+            ..DeployConfig::default()
+        };
+
+        let network = config.network_config().unwrap();
+
+        assert_eq!(network.name, "devnet");
+        assert_eq!(network.provider_url, "devnet.fuel.network");
+        assert_eq!(network.single_update_fee, 2);
+        assert!(network.data_sources.0.is_empty());
+    }
+
+    #[test]
+    fn reads_guardian_set_upgrade_vaa_file() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let vaa = LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(&guardians);
+        let path = temp_file(
+            "guardian_set_upgrade.hex",
+            &format!("0x{}\n", hex::encode(&vaa.0)),
+        );
+        let config = DeployConfig {
+            guardian_set_upgrade_vaa: Some(path),
+            ..DeployConfig::default()
+        };
+
+        let network = config.network_config().unwrap();
+        let upgrade = network.guardian_set_upgrade().unwrap();
+
+        assert_eq!(network.guardian_set_upgrade_vaa, vaa);
+        assert_eq!(upgrade.new_guardian_set_index, 1);
+        assert_eq!(upgrade.keys, guardians.addresses());
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[test]
+    fn fails_to_parse_unknown_network() {
+        let error = DeployConfig::from_toml("network = \"devnet\"").unwrap_err();
+
+        assert!(matches!(error, DeployError::Config(_)));
+    }
+
+    #[test]
+    fn fails_to_parse_invalid_emitter_address() {
+        let error =
+            DeployConfig::from_toml("[[data_sources]]\nchain_id = 1\nemitter_address = \"0x1234\"")
+                .unwrap_err();
+
+        assert!(matches!(error, DeployError::Config(_)));
+    }
+
+    #[test]
+    fn fails_to_read_missing_network_file() {
+        let config = DeployConfig {
+            network_file: Some(PathBuf::from("missing_network.toml")),
+            ..DeployConfig::default()
+        };
+
+        assert!(matches!(
+            config.network_config(),
+            Err(DeployError::Network(NetworkError::Read { .. }))
+        ));
+    }
+
+    #[test]
+    fn fails_to_read_missing_vaa_file() {
+        let config = DeployConfig {
+            guardian_set_upgrade_vaa: Some(PathBuf::from("missing_guardian_set_upgrade.hex")),
+            ..DeployConfig::default()
+        };
+
+        assert!(matches!(
+            config.network_config(),
+            Err(DeployError::Read { .. })
+        ));
+    }
+
+    #[test]
+    fn fails_to_decode_invalid_vaa() {
+        let not_hex = DeployConfig {
+            guardian_set_upgrade_vaa: Some(temp_file("not_hex.hex", "not hex")),
+            ..DeployConfig::default()
+        };
+        let not_vaa = DeployConfig {
+            guardian_set_upgrade_vaa: Some(temp_file("not_vaa.hex", "0102")),
+            ..DeployConfig::default()
+        };
+
+        assert!(matches!(not_hex.network_config(), Err(DeployError::Hex(_))));
+        assert!(not_vaa
+            .network_config()
+            .unwrap()
+            .guardian_set_upgrade()
+            .is_err());
+    }
+}
@@ -0,0 +1,55 @@
+use fuels::{
+    prelude::{Address, ViewOnlyAccount, WalletUnlocked, BASE_ASSET_ID},
+    test_helpers::{launch_custom_provider_and_get_wallets, WalletsConfig},
+};
+use pyth_sdk::{
+    deploy::{dry_run_deployment, DeployError},
+    pyth_utils::Pyth,
+};
+
+const SALT: [u8; 32] = [9; 32];
+
+async fn wallet() -> WalletUnlocked {
+    launch_custom_provider_and_get_wallets(WalletsConfig::default(), None, None)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap()
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn dry_runs_without_deploying() {
+        let wallet = wallet().await;
+        let balance = wallet.get_asset_balance(&BASE_ASSET_ID).await.unwrap();
+
+        dry_run_deployment(&wallet, SALT).await.unwrap();
+
+        let contract_id =
+            Pyth::deployment_contract_id(Address::from(wallet.address()), SALT).unwrap();
+        assert_eq!(
+            wallet.get_asset_balance(&BASE_ASSET_ID).await.unwrap(),
+            balance
+        );
+        assert!(Pyth::connect(contract_id, wallet).await.is_err());
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn fails_without_funds() {
+        let provider = wallet().await.provider().unwrap().clone();
+        let wallet = WalletUnlocked::new_random(Some(provider));
+
+        assert!(matches!(
+            dry_run_deployment(&wallet, SALT).await,
+            Err(DeployError::Fuels(_))
+        ));
+    }
+}
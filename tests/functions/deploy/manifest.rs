@@ -0,0 +1,139 @@
+use fuels::types::Bits256;
+use pyth_sdk::{
+    deploy::{ConstructorManifest, DeployError, DeploymentManifest, DeploymentTransactions},
+    network::DataSourceConfig,
+    pyth_utils::default_data_sources,
+    test_utils::LocalGuardianSet,
+};
+use serde_json::{json, Value};
+
+fn manifest(transactions: DeploymentTransactions) -> DeploymentManifest {
+    DeploymentManifest {
+        network: "local".to_string(),
+        provider_url: "127.0.0.1:4000".to_string(),
+        dry_run: transactions.constructor.is_none(),
+        contract_id: Bits256([1; 32]),
+        deployer: Bits256([2; 32]),
+        salt: Bits256([3; 32]),
+        transactions,
+        constructor: ConstructorManifest {
+            data_sources: vec![DataSourceConfig::from(&default_data_sources()[0])],
+            governance_data_source: DataSourceConfig::from(&default_data_sources()[1]),
+            single_update_fee: 1,
+            valid_time_period_seconds: 60,
+            wormhole_guardian_set_upgrade: "01".to_string(),
+            guardian_set_index: 4,
+        },
+    }
+}
+
+mod success {
+
+    use super::*;
+
+    #[test]
+    fn serializes_deployment() {
+        let manifest = manifest(DeploymentTransactions {
+            create: Some(Bits256([5; 32])),
+            constructor: Some(Bits256([4; 32])),
+        });
+
+        let json: Value = serde_json::from_str(&manifest.to_json().unwrap()).unwrap();
+
+        assert_eq!(
+            json,
+            json!({
+                "network": "local",
+                "provider_url": "127.0.0.1:4000",
+                "dry_run": false,
+                "contract_id": format!("0x{}", "01".repeat(32)),
+                "deployer": format!("0x{}", "02".repeat(32)),
+                "salt": format!("0x{}", "03".repeat(32)),
+                "transactions": {
+                    "create": format!("0x{}", "05".repeat(32)),
+                    "constructor": format!("0x{}", "04".repeat(32)),
+                },
+                "constructor": {
+                    "data_sources": [{
+                        "chain_id": 1,
+                        "emitter_address": "0x6bb14509a612f01fbbc4cffeebd4bbfb492a86df717ebe92eb6df432a3f00a25",
+                    }],
+                    "governance_data_source": {
+                        "chain_id": 26,
+                        "emitter_address": "0xf8cd23c2ab91237730770bbea08d61005cdda0984348f3f6eecb559638c0bba0",
+                    },
+                    "single_update_fee": 1,
+                    "valid_time_period_seconds": 60,
+                    "wormhole_guardian_set_upgrade": "01",
+                    "guardian_set_index": 4,
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let manifest = manifest(DeploymentTransactions {
+            create: Some(Bits256([5; 32])),
+            constructor: Some(Bits256([4; 32])),
+        });
+
+        let parsed = DeploymentManifest::from_json(&manifest.to_json().unwrap()).unwrap();
+
+        assert_eq!(parsed, manifest);
+    }
+
+    #[test]
+    fn decodes_guardian_set_upgrade_vaa() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let vaa = LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(&guardians);
+        let mut manifest = manifest(DeploymentTransactions::default());
+        manifest.constructor.wormhole_guardian_set_upgrade = hex::encode(&vaa.0);
+
+        let decoded = manifest
+            .constructor
+            .wormhole_guardian_set_upgrade()
+            .unwrap();
+
+        assert_eq!(decoded.encode(), vaa.0);
+    }
+
+    #[test]
+    fn serializes_dry_run_without_transactions() {
+        let manifest = manifest(DeploymentTransactions::default());
+
+        let json: Value = serde_json::from_str(&manifest.to_json().unwrap()).unwrap();
+
+        assert_eq!(json["dry_run"], true);
+        assert_eq!(json["transactions"]["create"], Value::Null);
+        assert_eq!(json["transactions"]["constructor"], Value::Null);
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[test]
+    fn fails_to_parse_invalid_contract_id() {
+        let mut json: Value = serde_json::from_str(
+            &manifest(DeploymentTransactions::default())
+                .to_json()
+                .unwrap(),
+        )
+        .unwrap();
+        json["contract_id"] = json!("0x1234");
+
+        assert!(DeploymentManifest::from_json(&json.to_string()).is_err());
+    }
+
+    #[test]
+    fn fails_to_decode_invalid_guardian_set_upgrade_vaa() {
+        let manifest = manifest(DeploymentTransactions::default());
+
+        assert!(matches!(
+            manifest.constructor.wormhole_guardian_set_upgrade(),
+            Err(DeployError::Decode(_))
+        ));
+    }
+}
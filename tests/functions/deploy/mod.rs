@@ -0,0 +1,4 @@
+pub(crate) mod config;
+pub(crate) mod dry_run;
+pub(crate) mod manifest;
+pub(crate) mod verify;
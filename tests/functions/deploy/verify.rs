@@ -0,0 +1,195 @@
+use fuels::{
+    prelude::{Address, WalletUnlocked},
+    test_helpers::{launch_custom_provider_and_get_wallets, WalletsConfig},
+    types::{Bits256, ContractId},
+};
+use pyth_sdk::{
+    deploy::{
+        verify_bytecode, verify_deployment, ConstructorManifest, DeploymentManifest,
+        DeploymentTransactions, VerificationCheck, VerificationReport,
+    },
+    network::NetworkConfig,
+    pyth_utils::Pyth,
+    test_utils::LocalGuardianSet,
+};
+
+const SALT: [u8; 32] = [9; 32];
+
+async fn wallet() -> WalletUnlocked {
+    launch_custom_provider_and_get_wallets(WalletsConfig::default(), None, None)
+        .await
+        .unwrap()
+        .pop()
+        .unwrap()
+}
+
+/// Deploys and constructs the contract, returning it along with the manifest of the deployment.
+async fn deploy() -> (Pyth<WalletUnlocked>, DeploymentManifest) {
+    let wallet = wallet().await;
+    let deployer = Address::from(wallet.address());
+    let guardians = LocalGuardianSet::random(1, 4);
+    let network = NetworkConfig::local().with_guardian_set_upgrade_vaa(
+        LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(&guardians),
+    );
+
+    let (pyth, create_tx_id) = Pyth::deploy_with_salt_and_tx_id(wallet, SALT)
+        .await
+        .unwrap();
+    let response = pyth.constructor_with_network(&network).await.unwrap();
+
+    let manifest = DeploymentManifest {
+        network: network.name.clone(),
+        provider_url: network.provider_url.clone(),
+        dry_run: false,
+        contract_id: Bits256(*ContractId::from(pyth.instance.contract_id())),
+        deployer: Bits256(*deployer),
+        salt: Bits256(SALT),
+        transactions: DeploymentTransactions {
+            create: Some(Bits256(*create_tx_id)),
+            constructor: response.response.tx_id.map(|tx_id| Bits256(*tx_id)),
+        },
+        constructor: ConstructorManifest {
+            data_sources: network.data_sources.0.clone(),
+            governance_data_source: network.governance_data_source,
+            single_update_fee: network.single_update_fee,
+            valid_time_period_seconds: network.valid_time_period_seconds,
+            wormhole_guardian_set_upgrade: hex::encode(&network.guardian_set_upgrade_vaa.0),
+            guardian_set_index: guardians.index(),
+        },
+    };
+
+    (pyth, manifest)
+}
+
+fn failed_checks(report: &VerificationReport) -> Vec<&str> {
+    report.failures().map(|check| check.name.as_str()).collect()
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn verifies_deployment() {
+        let (pyth, manifest) = deploy().await;
+
+        let report = verify_deployment(&pyth, &manifest).await.unwrap();
+
+        assert!(report.passed(), "{report}");
+        assert_eq!(report.checks.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn verifies_deployed_bytecode() {
+        let wallet = wallet().await;
+        let provider = wallet.provider().unwrap().clone();
+        let deployer = Address::from(wallet.address());
+        let pyth = Pyth::deploy_with_salt(wallet, SALT).await.unwrap();
+
+        let check = verify_bytecode(
+            &provider,
+            ContractId::from(pyth.instance.contract_id()),
+            deployer,
+        )
+        .await
+        .unwrap();
+
+        assert!(check.passed(), "{check:?}");
+    }
+
+    #[test]
+    fn displays_diff_of_failed_checks() {
+        let report = VerificationReport {
+            checks: vec![
+                VerificationCheck {
+                    name: "single_update_fee".to_string(),
+                    expected: "1".to_string(),
+                    actual: "1".to_string(),
+                },
+                VerificationCheck {
+                    name: "valid_time_period".to_string(),
+                    expected: "60".to_string(),
+                    actual: "120".to_string(),
+                },
+            ],
+        };
+
+        assert!(!report.passed());
+        assert_eq!(
+            report.to_string(),
+            "PASS single_update_fee\nFAIL valid_time_period\n  - 60\n  + 120\n1 of 2 checks failed"
+        );
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reports_misconfigured_parameters() {
+        let (pyth, mut manifest) = deploy().await;
+        manifest.constructor.single_update_fee += 1;
+        manifest.constructor.data_sources.pop();
+        manifest.constructor.governance_data_source.chain_id += 1;
+
+        let report = verify_deployment(&pyth, &manifest).await.unwrap();
+
+        assert_eq!(
+            failed_checks(&report),
+            vec![
+                "valid_data_sources",
+                "governance_data_source",
+                "single_update_fee"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_other_guardian_set() {
+        let (pyth, mut manifest) = deploy().await;
+        let other_guardians = LocalGuardianSet::random(1, 4);
+        manifest.constructor.wormhole_guardian_set_upgrade = hex::encode(
+            LocalGuardianSet::random(0, 1)
+                .guardian_set_upgrade_vaa(&other_guardians)
+                .0,
+        );
+
+        let report = verify_deployment(&pyth, &manifest).await.unwrap();
+
+        assert_eq!(failed_checks(&report), vec!["guardian_set(1)"]);
+    }
+
+    #[tokio::test]
+    async fn reports_other_contract_id() {
+        let (pyth, mut manifest) = deploy().await;
+        manifest.salt = Bits256([10; 32]);
+
+        let report = verify_deployment(&pyth, &manifest).await.unwrap();
+
+        assert_eq!(failed_checks(&report), vec!["contract_id"]);
+    }
+
+    #[tokio::test]
+    async fn reports_other_bytecode() {
+        let (pyth, mut manifest) = deploy().await;
+        manifest.deployer = Bits256([10; 32]);
+
+        let report = verify_deployment(&pyth, &manifest).await.unwrap();
+
+        assert_eq!(failed_checks(&report), vec!["contract_id", "bytecode"]);
+    }
+
+    #[tokio::test]
+    async fn reports_missing_bytecode() {
+        let wallet = wallet().await;
+        let provider = wallet.provider().unwrap();
+
+        let check = verify_bytecode(provider, ContractId::zeroed(), wallet.address().into())
+            .await
+            .unwrap();
+
+        assert!(!check.passed());
+        assert_eq!(check.actual, "no contract deployed");
+    }
+}
@@ -0,0 +1,143 @@
+use crate::utils::{
+    interface::{pyth_core::update_fee, pyth_init::constructor},
+    setup::{setup_environment, setup_local_environment},
+};
+use pyth_sdk::{
+    accumulator::AccumulatorUpdate,
+    constants::{DEFAULT_SINGLE_UPDATE_FEE, DEFAULT_VALID_TIME_PERIOD},
+    decode::DecodeError,
+    fee::{compute_update_fee, number_of_updates, FeeError},
+    pyth_utils::{
+        default_data_sources, default_price_feed_ids, guardian_set_upgrade_3_vaa,
+        test_accumulator_update_data_bytes, test_batch_update_data_bytes,
+    },
+    test_utils::{now, LocalGuardianSet, UpdateDataBuilder},
+};
+
+mod success {
+
+    use super::*;
+
+    #[test]
+    fn counts_each_batch_attestation_update_once() {
+        let update_data = test_batch_update_data_bytes();
+
+        assert_eq!(
+            compute_update_fee(&update_data, 3).unwrap(),
+            3 * update_data.len() as u64
+        );
+    }
+
+    #[test]
+    fn counts_accumulator_updates() {
+        let update_data = test_accumulator_update_data_bytes();
+        let update = AccumulatorUpdate::parse(&update_data[0].0).unwrap();
+
+        assert_eq!(
+            number_of_updates(&update_data[0].0).unwrap(),
+            update.updates.len() as u64
+        );
+        assert_eq!(
+            compute_update_fee(&update_data, 3).unwrap(),
+            3 * update.updates.len() as u64
+        );
+    }
+
+    #[tokio::test]
+    async fn matches_contract_for_test_update_data() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            guardian_set_upgrade_3_vaa(),
+        )
+        .await;
+
+        for update_data in [
+            test_batch_update_data_bytes(),
+            test_accumulator_update_data_bytes(),
+            [
+                test_batch_update_data_bytes(),
+                test_accumulator_update_data_bytes(),
+            ]
+            .concat(),
+        ] {
+            assert_eq!(
+                compute_update_fee(&update_data, DEFAULT_SINGLE_UPDATE_FEE).unwrap(),
+                update_fee(&deployer.instance, update_data.clone())
+                    .await
+                    .value
+            );
+            assert_eq!(
+                deployer.compute_update_fee(&update_data).await.unwrap(),
+                deployer.update_fee(&update_data).await.unwrap().value
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn matches_contract_for_built_update_data() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let publish_time = now() as i64;
+
+        for number_of_price_feeds in 1..=default_price_feed_ids().len() {
+            let update_data = vec![UpdateDataBuilder::new(guardians.clone())
+                .with_price_feeds(
+                    default_price_feed_ids()
+                        .into_iter()
+                        .take(number_of_price_feeds)
+                        .map(|id| (id, 100_000, 10, -5, publish_time, 99_000, 20)),
+                )
+                .build()];
+
+            assert_eq!(
+                deployer.compute_update_fee(&update_data).await.unwrap(),
+                deployer.update_fee(&update_data).await.unwrap().value
+            );
+        }
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[test]
+    fn fails_for_truncated_accumulator_update() {
+        let mut data = test_accumulator_update_data_bytes()[0].0.clone();
+        data.truncate(16);
+
+        assert!(matches!(
+            number_of_updates(&data),
+            Err(DecodeError::UnexpectedEnd { .. })
+        ));
+    }
+
+    #[test]
+    fn fails_for_unsupported_major_version() {
+        let mut data = test_accumulator_update_data_bytes()[0].0.clone();
+        data[4] = 2;
+
+        assert_eq!(
+            number_of_updates(&data),
+            Err(DecodeError::InvalidMajorVersion(2))
+        );
+    }
+
+    #[test]
+    fn fails_when_fee_overflows() {
+        let update_data = test_batch_update_data_bytes();
+
+        assert_eq!(
+            compute_update_fee(&update_data, u64::MAX),
+            Err(FeeError::Overflow {
+                number_of_updates: update_data.len() as u64,
+                single_update_fee: u64::MAX,
+            })
+        );
+    }
+}
@@ -0,0 +1 @@
+pub(crate) mod compute_update_fee;
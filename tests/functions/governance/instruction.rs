@@ -0,0 +1,163 @@
+use fuels::types::{Address, ContractId, Identity};
+use pyth_sdk::{
+    decode::DecodeError,
+    governance::{GovernanceAction, GovernanceInstruction, ALL_CHAINS, GOVERNANCE_MAGIC},
+    pyth_utils::default_data_sources,
+};
+
+mod success {
+
+    use super::*;
+
+    #[test]
+    fn encodes_set_fee() {
+        let instruction =
+            GovernanceInstruction::new(GovernanceAction::SetFee { value: 5, expo: 2 });
+
+        assert_eq!(
+            hex::encode(instruction.encode()),
+            "5054474d0103000000000000000000050000000000000002"
+        );
+    }
+
+    #[test]
+    fn round_trips_actions() {
+        for action in [
+            GovernanceAction::SetDataSources(default_data_sources()),
+            GovernanceAction::SetFee { value: 1, expo: 3 },
+            GovernanceAction::SetValidPeriod {
+                valid_time_period_seconds: 120,
+            },
+            GovernanceAction::WithdrawFees {
+                amount: 100,
+                recipient: Identity::Address(Address::new([1; 32])),
+            },
+            GovernanceAction::WithdrawFees {
+                amount: 100,
+                recipient: Identity::ContractId(ContractId::new([2; 32])),
+            },
+        ] {
+            let instruction = GovernanceInstruction::new(action);
+
+            assert_eq!(
+                GovernanceInstruction::parse(&instruction.encode()).unwrap(),
+                instruction
+            );
+        }
+    }
+
+    #[test]
+    fn encodes_withdraw_fees() {
+        let instruction = GovernanceInstruction::new(GovernanceAction::WithdrawFees {
+            amount: 100,
+            recipient: Identity::ContractId(ContractId::new([2; 32])),
+        });
+
+        assert_eq!(
+            hex::encode(instruction.encode()),
+            format!("5054474d01090000000000000000006401{}", "02".repeat(32))
+        );
+    }
+
+    #[test]
+    fn parses_target_chain_id() {
+        let mut instruction = GovernanceInstruction::new(GovernanceAction::SetValidPeriod {
+            valid_time_period_seconds: 60,
+        });
+        assert_eq!(instruction.target_chain_id, ALL_CHAINS);
+        instruction.target_chain_id = 2;
+
+        let parsed = GovernanceInstruction::parse(&instruction.encode()).unwrap();
+
+        assert_eq!(parsed.target_chain_id, 2);
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    fn set_valid_period() -> Vec<u8> {
+        GovernanceInstruction::new(GovernanceAction::SetValidPeriod {
+            valid_time_period_seconds: 60,
+        })
+        .encode()
+    }
+
+    #[test]
+    fn fails_to_parse_invalid_magic() {
+        let mut payload = set_valid_period();
+        payload[0] = b'X';
+
+        assert_eq!(
+            GovernanceInstruction::parse(&payload),
+            Err(DecodeError::InvalidMagic {
+                expected: GOVERNANCE_MAGIC,
+                found: *b"XTGM",
+            })
+        );
+    }
+
+    #[test]
+    fn fails_to_parse_other_module() {
+        let mut payload = set_valid_period();
+        payload[4] = 0;
+
+        assert_eq!(
+            GovernanceInstruction::parse(&payload),
+            Err(DecodeError::InvalidGovernanceModule(0))
+        );
+    }
+
+    #[test]
+    fn fails_to_parse_unsupported_action() {
+        let mut payload = set_valid_period();
+        payload[5] = 1;
+
+        assert_eq!(
+            GovernanceInstruction::parse(&payload),
+            Err(DecodeError::InvalidGovernanceAction(1))
+        );
+    }
+
+    #[test]
+    fn fails_to_parse_unknown_recipient_type() {
+        let mut payload = GovernanceInstruction::new(GovernanceAction::WithdrawFees {
+            amount: 100,
+            recipient: Identity::Address(Address::new([1; 32])),
+        })
+        .encode();
+        payload[16] = 2;
+
+        assert_eq!(
+            GovernanceInstruction::parse(&payload),
+            Err(DecodeError::InvalidRecipientType(2))
+        );
+    }
+
+    #[test]
+    fn fails_to_parse_truncated_or_trailing_payload() {
+        let payload = set_valid_period();
+        let mut trailing = payload.clone();
+        trailing.push(0);
+
+        assert!(matches!(
+            GovernanceInstruction::parse(&payload[..payload.len() - 1]),
+            Err(DecodeError::UnexpectedEnd { .. })
+        ));
+        assert_eq!(
+            GovernanceInstruction::parse(&trailing),
+            Err(DecodeError::TrailingBytes(1))
+        );
+    }
+
+    #[test]
+    fn fails_to_parse_empty_data_sources() {
+        let payload = GovernanceInstruction::new(GovernanceAction::SetDataSources(vec![])).encode();
+
+        assert_eq!(
+            GovernanceInstruction::parse(&payload),
+            Err(DecodeError::NoDataSources)
+        );
+    }
+}
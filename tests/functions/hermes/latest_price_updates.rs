@@ -0,0 +1,152 @@
+use crate::utils::hermes::HermesStub;
+use axum::http::StatusCode;
+use pyth_sdk::{
+    constants::{TEST_ACCUMULATOR_ETH_USD_PRICE_FEED, TEST_ACCUMULATOR_USDC_USD_PRICE_FEED},
+    hermes::{EncodingType, HermesClient, HermesConfig, HermesError},
+    pyth_utils::{default_price_feed_ids, test_accumulator_update_data_bytes},
+};
+use std::time::Duration;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn gets_hex_encoded_price_updates() {
+        let hermes = HermesStub::accumulator().start().await;
+
+        let price_update = hermes
+            .client()
+            .latest_price_updates(&default_price_feed_ids(), EncodingType::Hex, false)
+            .await
+            .unwrap();
+
+        assert_eq!(price_update.binary.encoding, EncodingType::Hex);
+        assert_eq!(
+            price_update.update_data().unwrap(),
+            test_accumulator_update_data_bytes()
+        );
+        assert!(price_update.parsed.is_none());
+    }
+
+    #[tokio::test]
+    async fn gets_base64_encoded_price_updates() {
+        let hermes = HermesStub::accumulator().start().await;
+
+        let price_update = hermes
+            .client()
+            .latest_price_updates(&default_price_feed_ids(), EncodingType::Base64, false)
+            .await
+            .unwrap();
+
+        assert_eq!(price_update.binary.encoding, EncodingType::Base64);
+        assert_eq!(
+            price_update.update_data().unwrap(),
+            test_accumulator_update_data_bytes()
+        );
+    }
+
+    #[tokio::test]
+    async fn gets_parsed_price_feeds() {
+        let hermes = HermesStub::accumulator().start().await;
+
+        let price_update = hermes
+            .client()
+            .latest_price_updates(&default_price_feed_ids(), EncodingType::Hex, true)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            price_update.price_feeds().unwrap(),
+            vec![
+                TEST_ACCUMULATOR_ETH_USD_PRICE_FEED,
+                TEST_ACCUMULATOR_USDC_USD_PRICE_FEED
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn requests_given_price_feed_ids() {
+        let hermes = HermesStub::accumulator().start().await;
+
+        let update_data = hermes
+            .client()
+            .latest_update_data(&default_price_feed_ids())
+            .await
+            .unwrap();
+
+        assert_eq!(update_data, test_accumulator_update_data_bytes());
+        assert_eq!(
+            hermes.requested_ids(),
+            vec![default_price_feed_ids()
+                .iter()
+                .map(|id| hex::encode(id.0))
+                .collect::<Vec<String>>()]
+        );
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[test]
+    fn fails_when_base_url_is_invalid() {
+        let result = HermesClient::new(HermesConfig::default().with_base_url("not a url"));
+
+        assert!(matches!(result, Err(HermesError::InvalidUrl(_))));
+    }
+
+    #[tokio::test]
+    async fn fails_when_parsed_section_is_missing() {
+        let hermes = HermesStub::accumulator().start().await;
+
+        let price_update = hermes
+            .client()
+            .latest_price_updates(&default_price_feed_ids(), EncodingType::Hex, false)
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            price_update.price_feeds(),
+            Err(HermesError::MissingParsedSection)
+        ));
+    }
+
+    #[tokio::test]
+    async fn fails_on_unsuccessful_status() {
+        let hermes = HermesStub::accumulator()
+            .with_status(StatusCode::SERVICE_UNAVAILABLE)
+            .start()
+            .await;
+
+        let error = hermes
+            .client()
+            .latest_update_data(&default_price_feed_ids())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, HermesError::Status { status: 503, .. }));
+    }
+
+    #[tokio::test]
+    async fn times_out_on_slow_response() {
+        let hermes = HermesStub::accumulator()
+            .with_delay(Duration::from_millis(500))
+            .start()
+            .await;
+        let client = HermesClient::new(
+            HermesConfig::default()
+                .with_base_url(hermes.url())
+                .with_request_timeout(Duration::from_millis(100)),
+        )
+        .unwrap();
+
+        let error = client
+            .latest_update_data(&default_price_feed_ids())
+            .await
+            .unwrap_err();
+
+        assert!(error.is_timeout());
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod latest_price_updates;
+pub(crate) mod subscribe;
@@ -0,0 +1,110 @@
+use crate::utils::hermes::{HermesStub, StubUpdate};
+use axum::http::StatusCode;
+use futures::StreamExt;
+use pyth_sdk::{
+    constants::{
+        TEST_ACCUMULATOR_ETH_USD_PRICE_FEED, TEST_ACCUMULATOR_USDC_USD_PRICE_FEED,
+        TEST_BATCH_ETH_USD_PRICE_FEED, TEST_BATCH_USDC_USD_PRICE_FEED,
+    },
+    hermes::{HermesError, SubscriptionConfig},
+    pyth_utils::{
+        default_price_feed_ids, test_accumulator_update_data_bytes, test_batch_update_data_bytes,
+    },
+};
+use std::time::Duration;
+
+fn test_config() -> SubscriptionConfig {
+    SubscriptionConfig::default()
+        .with_idle_timeout(Duration::from_secs(5))
+        .with_reconnect_delay(Duration::from_millis(10))
+        .with_max_reconnect_delay(Duration::from_millis(50))
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn yields_update_data_and_price_feeds() {
+        let hermes = HermesStub::accumulator()
+            .with_stream(vec![vec![StubUpdate::accumulator()]])
+            .start()
+            .await;
+
+        let mut updates = Box::pin(
+            hermes
+                .client()
+                .subscribe_with_config(&default_price_feed_ids(), test_config()),
+        );
+        let update = updates.next().await.unwrap().unwrap();
+
+        assert_eq!(update.update_data, test_accumulator_update_data_bytes());
+        assert_eq!(
+            update.price_feeds,
+            vec![
+                TEST_ACCUMULATOR_ETH_USD_PRICE_FEED,
+                TEST_ACCUMULATOR_USDC_USD_PRICE_FEED
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn reconnects_and_skips_replayed_updates() {
+        let hermes = HermesStub::accumulator()
+            .with_stream(vec![
+                vec![StubUpdate::batch()],
+                vec![StubUpdate::batch(), StubUpdate::accumulator()],
+            ])
+            .start()
+            .await;
+
+        let mut updates = Box::pin(
+            hermes
+                .client()
+                .subscribe_with_config(&default_price_feed_ids(), test_config()),
+        );
+        let first = updates.next().await.unwrap().unwrap();
+        let second = updates.next().await.unwrap().unwrap();
+
+        assert_eq!(first.update_data, test_batch_update_data_bytes());
+        assert_eq!(
+            first.price_feeds,
+            vec![
+                TEST_BATCH_ETH_USD_PRICE_FEED,
+                TEST_BATCH_USDC_USD_PRICE_FEED
+            ]
+        );
+        assert_eq!(second.update_data, test_accumulator_update_data_bytes());
+        assert_eq!(hermes.stream_connections(), 2);
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn gives_up_after_max_reconnect_attempts() {
+        let hermes = HermesStub::accumulator()
+            .with_status(StatusCode::SERVICE_UNAVAILABLE)
+            .start()
+            .await;
+
+        let mut updates = Box::pin(hermes.client().subscribe_with_config(
+            &default_price_feed_ids(),
+            test_config().with_max_reconnect_attempts(2),
+        ));
+        let error = updates.next().await.unwrap().unwrap_err();
+
+        let HermesError::ReconnectAttemptsExhausted { attempts, source } = error else {
+            panic!("expected exhausted reconnect attempts, got {error:?}");
+        };
+        assert_eq!(attempts, 2);
+        assert!(matches!(
+            source.as_deref(),
+            Some(HermesError::Status { status: 503, .. })
+        ));
+        assert_eq!(hermes.stream_connections(), 3);
+        assert!(updates.next().await.is_none());
+    }
+}
@@ -0,0 +1,125 @@
+use pyth_sdk::{
+    constants::{BETA_5_PYTH_CONTRACT_ID, BETA_5_URL, TAI64_DIFFERENCE},
+    hermes::DEFAULT_HERMES_URL,
+    keeper::{
+        deviation_bps, FeedConfig, KeeperConfig, UpdateReason, DEFAULT_POLL_INTERVAL_SECONDS,
+    },
+    pyth_utils::{default_price_feed_ids, Price},
+};
+
+const EXAMPLE_CONFIG: &str = include_str!("../../../scripts/keeper.example.json");
+
+fn feed() -> FeedConfig {
+    FeedConfig {
+        id: default_price_feed_ids()[0],
+        heartbeat_seconds: 60,
+        deviation_bps: 50,
+    }
+}
+
+fn price(price: i64, publish_time: u64) -> Price {
+    Price {
+        confidence: 10,
+        exponent: 8,
+        price: price as u64,
+        publish_time: publish_time + TAI64_DIFFERENCE,
+    }
+}
+
+mod success {
+
+    use super::*;
+
+    #[test]
+    fn parses_example_config() {
+        let config = KeeperConfig::from_json(EXAMPLE_CONFIG).unwrap();
+
+        assert_eq!(config.contract_id, BETA_5_PYTH_CONTRACT_ID);
+        assert_eq!(config.feeds.len(), 2);
+        assert_eq!(config.feeds[0].id, default_price_feed_ids()[0]);
+        assert_eq!(config.feeds[0].heartbeat_seconds, 60);
+        assert_eq!(config.feeds[0].deviation_bps, 50);
+    }
+
+    #[test]
+    fn defaults_to_beta_5_and_hermes() {
+        let config = KeeperConfig::from_json(r#"{ "feeds": [] }"#).unwrap();
+
+        assert_eq!(config.provider_url, BETA_5_URL);
+        assert_eq!(config.contract_id, BETA_5_PYTH_CONTRACT_ID);
+        assert_eq!(config.hermes_url, DEFAULT_HERMES_URL);
+        assert_eq!(config.poll_interval_seconds, DEFAULT_POLL_INTERVAL_SECONDS);
+    }
+
+    #[test]
+    fn updates_missing_price_feed() {
+        assert_eq!(
+            feed().update_reason(None, &price(100_000, 1_000)),
+            Some(UpdateReason::Missing)
+        );
+    }
+
+    #[test]
+    fn updates_after_heartbeat() {
+        assert_eq!(
+            feed().update_reason(Some(&price(100_000, 1_000)), &price(100_000, 1_060)),
+            Some(UpdateReason::Heartbeat)
+        );
+    }
+
+    #[test]
+    fn updates_on_deviation() {
+        assert_eq!(
+            feed().update_reason(Some(&price(100_000, 1_000)), &price(100_500, 1_001)),
+            Some(UpdateReason::Deviation)
+        );
+        assert_eq!(
+            feed().update_reason(Some(&price(-100_000, 1_000)), &price(-99_500, 1_001)),
+            Some(UpdateReason::Deviation)
+        );
+    }
+
+    #[test]
+    fn skips_fresh_price_within_deviation() {
+        assert_eq!(
+            feed().update_reason(Some(&price(100_000, 1_000)), &price(100_499, 1_059)),
+            None
+        );
+    }
+
+    #[test]
+    fn skips_price_that_is_not_newer() {
+        assert_eq!(
+            feed().update_reason(Some(&price(100_000, 1_000)), &price(200_000, 1_000)),
+            None
+        );
+    }
+
+    #[test]
+    fn computes_deviation_in_basis_points() {
+        assert_eq!(deviation_bps(&price(100_000, 0), &price(101_000, 0)), 100);
+        assert_eq!(deviation_bps(&price(100_000, 0), &price(99_000, 0)), 100);
+        assert_eq!(deviation_bps(&price(0, 0), &price(0, 0)), 0);
+        assert_eq!(deviation_bps(&price(0, 0), &price(1, 0)), u64::MAX);
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[test]
+    fn fails_to_parse_invalid_price_feed_id() {
+        let error = KeeperConfig::from_json(
+            r#"{ "feeds": [{ "id": "not hex", "heartbeat_seconds": 60, "deviation_bps": 50 }] }"#,
+        )
+        .unwrap_err();
+
+        assert!(error.to_string().contains("invalid price feed id"));
+    }
+
+    #[test]
+    fn fails_to_parse_config_without_feeds() {
+        assert!(KeeperConfig::from_json("{}").is_err());
+    }
+}
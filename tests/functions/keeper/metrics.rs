@@ -0,0 +1,221 @@
+use crate::utils::{
+    hermes::HermesStub,
+    keeper::{feeds, keeper, stub_update},
+    setup::setup_local_environment,
+};
+use pyth_sdk::{
+    constants::DEFAULT_VALID_TIME_PERIOD,
+    keeper::{
+        revert_error, router, FeedStaleness, FeedUpdate, Health, KeeperMetrics, KeeperObservation,
+        KeeperStep, UpdateReason, UNKNOWN_REVERT_ERROR,
+    },
+    test_utils::{now, LocalGuardianSet},
+};
+use reqwest::StatusCode;
+use std::{net::TcpListener, sync::Arc};
+
+fn observation(staleness: &[Option<u64>]) -> KeeperObservation {
+    KeeperObservation {
+        block_time: now(),
+        valid_time_period: DEFAULT_VALID_TIME_PERIOD,
+        balance: 1_000,
+        feeds: feeds()
+            .iter()
+            .zip(staleness)
+            .map(|(feed, staleness)| FeedStaleness {
+                id: feed.id,
+                staleness: *staleness,
+            })
+            .collect(),
+    }
+}
+
+/// Serves `router(metrics)` on a local port and returns its URL.
+fn serve(metrics: Arc<KeeperMetrics>) -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.set_nonblocking(true).unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+
+    tokio::spawn(
+        axum::Server::from_tcp(listener)
+            .unwrap()
+            .serve(router(metrics).into_make_service()),
+    );
+
+    url
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn counts_updates_fees_and_reverts() {
+        let metrics = KeeperMetrics::new().unwrap();
+        let updates = vec![
+            FeedUpdate {
+                id: feeds()[0].id,
+                publish_time: 0,
+                reason: UpdateReason::Missing,
+            },
+            FeedUpdate {
+                id: feeds()[1].id,
+                publish_time: 0,
+                reason: UpdateReason::Deviation,
+            },
+        ];
+
+        metrics.record_step(&KeeperStep::Updated {
+            updates: updates.clone(),
+            fee: 2,
+        });
+        metrics.record_step(&KeeperStep::Reverted {
+            updates,
+            reason: "InsufficientFee".to_string(),
+        });
+        let encoded = metrics.encode().unwrap();
+
+        assert!(encoded.contains("pyth_keeper_updates_total{reason=\"missing\"} 1"));
+        assert!(encoded.contains("pyth_keeper_updates_total{reason=\"deviation\"} 1"));
+        assert!(encoded.contains("pyth_keeper_fees_spent_total 2"));
+        assert!(encoded.contains("pyth_keeper_reverts_total{error=\"InsufficientFee\"} 1"));
+    }
+
+    #[tokio::test]
+    async fn records_staleness_balance_and_health() {
+        let metrics = KeeperMetrics::new().unwrap();
+
+        assert_eq!(metrics.health(), Health::Unknown);
+
+        metrics.record_observation(&observation(&[Some(5), Some(DEFAULT_VALID_TIME_PERIOD)]));
+        let encoded = metrics.encode().unwrap();
+
+        assert_eq!(metrics.health(), Health::Healthy);
+        assert!(encoded.contains(&format!(
+            "pyth_keeper_feed_staleness_seconds{{feed=\"0x{}\"}} 5",
+            hex::encode(feeds()[0].id.0)
+        )));
+        assert!(encoded.contains("pyth_keeper_wallet_balance 1000"));
+        assert!(encoded.contains(&format!(
+            "pyth_keeper_valid_time_period_seconds {DEFAULT_VALID_TIME_PERIOD}"
+        )));
+    }
+
+    #[tokio::test]
+    async fn revert_error_labels_error_variants() {
+        assert_eq!(revert_error("OutdatedPrice"), "OutdatedPrice");
+        assert_eq!(revert_error("SignatureInvalid"), "SignatureInvalid");
+        assert_eq!(revert_error("CannotReinitialized"), "CannotReinitialized");
+        assert_eq!(revert_error("NotAnError"), UNKNOWN_REVERT_ERROR);
+        assert_eq!(revert_error("Revert(123)"), UNKNOWN_REVERT_ERROR);
+        assert_eq!(revert_error(""), UNKNOWN_REVERT_ERROR);
+    }
+
+    #[tokio::test]
+    async fn observes_on_chain_staleness() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let publish_time = now() as i64 - 10;
+        let hermes = HermesStub::new(stub_update(&guardians, 100_000, publish_time))
+            .start()
+            .await;
+        let keeper = keeper(&deployer, &hermes).await;
+
+        let before = keeper.observe().await.unwrap();
+        keeper.step().await.unwrap();
+        let after = keeper.observe().await.unwrap();
+
+        assert_eq!(before.valid_time_period, DEFAULT_VALID_TIME_PERIOD);
+        assert!(before.feeds.iter().all(|feed| feed.staleness.is_none()));
+        assert!(before.balance > after.balance);
+        for feed in after.feeds {
+            assert!(feed.staleness.is_some());
+            assert!(!feed.is_stale(after.valid_time_period));
+        }
+    }
+
+    #[tokio::test]
+    async fn serves_metrics_and_health() {
+        let metrics = Arc::new(KeeperMetrics::new().unwrap());
+        metrics.record_observation(&observation(&[Some(5), Some(5)]));
+        let url = serve(metrics);
+
+        let health = reqwest::get(format!("{url}/health")).await.unwrap();
+        let encoded = reqwest::get(format!("{url}/metrics"))
+            .await
+            .unwrap()
+            .text()
+            .await
+            .unwrap();
+
+        assert_eq!(health.status(), StatusCode::OK);
+        assert!(encoded.contains("pyth_keeper_feed_staleness_seconds"));
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn health_fails_on_stale_price_feeds() {
+        let metrics = KeeperMetrics::new().unwrap();
+
+        metrics.record_observation(&observation(&[
+            Some(5),
+            Some(DEFAULT_VALID_TIME_PERIOD + 1),
+        ]));
+
+        assert_eq!(metrics.health(), Health::Stale(vec![feeds()[1].id]));
+    }
+
+    #[tokio::test]
+    async fn health_fails_on_missing_price_feeds() {
+        let metrics = KeeperMetrics::new().unwrap();
+
+        metrics.record_observation(&observation(&[None, Some(5)]));
+
+        assert_eq!(metrics.health(), Health::Stale(vec![feeds()[0].id]));
+        assert!(!metrics
+            .encode()
+            .unwrap()
+            .contains(&hex::encode(feeds()[0].id.0)));
+    }
+
+    #[tokio::test]
+    async fn serves_unavailable_health_until_observed_and_when_stale() {
+        let metrics = Arc::new(KeeperMetrics::new().unwrap());
+        let url = serve(metrics.clone());
+
+        let unobserved = reqwest::get(format!("{url}/health")).await.unwrap();
+        metrics.record_observation(&observation(&[Some(5), None]));
+        let stale = reqwest::get(format!("{url}/health")).await.unwrap();
+        let stale_status = stale.status();
+        let stale_body = stale.text().await.unwrap();
+
+        assert_eq!(unobserved.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(stale_status, StatusCode::SERVICE_UNAVAILABLE);
+        assert!(stale_body.contains(&hex::encode(feeds()[1].id.0)));
+    }
+
+    #[tokio::test]
+    async fn serves_unavailable_health_after_failed_observation() {
+        let metrics = Arc::new(KeeperMetrics::new().unwrap());
+        let url = serve(metrics.clone());
+
+        metrics.record_observation(&observation(&[Some(5), Some(5)]));
+        metrics.record_observation_error(&"connection refused");
+        let response = reqwest::get(format!("{url}/health")).await.unwrap();
+
+        assert_eq!(
+            metrics.health(),
+            Health::ObservationFailed("connection refused".to_string())
+        );
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert!(response
+            .text()
+            .await
+            .unwrap()
+            .contains("connection refused"));
+    }
+}
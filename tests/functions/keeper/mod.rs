@@ -0,0 +1,3 @@
+pub(crate) mod config;
+pub(crate) mod metrics;
+pub(crate) mod step;
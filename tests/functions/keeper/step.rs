@@ -0,0 +1,174 @@
+use crate::utils::{
+    hermes::HermesStub,
+    interface::pyth_init::constructor,
+    keeper::{feeds, keeper, stub_update},
+    setup::{setup_environment, setup_local_environment},
+};
+use pyth_sdk::{
+    constants::{DEFAULT_VALID_TIME_PERIOD, TAI64_DIFFERENCE},
+    keeper::{KeeperStep, UpdateReason},
+    pyth_utils::default_data_sources,
+    test_utils::{now, LocalGuardianSet},
+};
+
+fn reasons(step: &KeeperStep) -> Vec<UpdateReason> {
+    match step {
+        KeeperStep::Updated { updates, .. } | KeeperStep::Reverted { updates, .. } => {
+            updates.iter().map(|update| update.reason).collect()
+        }
+        _ => vec![],
+    }
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn updates_missing_price_feeds() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let publish_time = now() as i64;
+        let hermes = HermesStub::new(stub_update(&guardians, 100_000, publish_time))
+            .start()
+            .await;
+
+        let step = keeper(&deployer, &hermes).await.step().await.unwrap();
+
+        assert_eq!(reasons(&step), vec![UpdateReason::Missing; 2]);
+        assert!(matches!(step, KeeperStep::Updated { fee: 2, .. }));
+        for feed in feeds() {
+            assert_eq!(
+                deployer.latest_publish_time(feed.id).await.unwrap().value,
+                publish_time as u64 + TAI64_DIFFERENCE
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn skips_up_to_date_price_feeds() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let publish_time = now() as i64;
+        let hermes = HermesStub::new(stub_update(&guardians, 100_000, publish_time))
+            .start()
+            .await;
+        let first_keeper = keeper(&deployer, &hermes).await;
+
+        first_keeper.step().await.unwrap();
+        let newer_hermes = HermesStub::new(stub_update(&guardians, 100_100, publish_time + 1))
+            .start()
+            .await;
+
+        assert_eq!(first_keeper.step().await.unwrap(), KeeperStep::UpToDate);
+        assert_eq!(
+            keeper(&deployer, &newer_hermes).await.step().await.unwrap(),
+            KeeperStep::UpToDate
+        );
+    }
+
+    #[tokio::test]
+    async fn updates_deviating_price_feeds() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let publish_time = now() as i64;
+        let hermes = HermesStub::new(stub_update(&guardians, 100_000, publish_time))
+            .start()
+            .await;
+        keeper(&deployer, &hermes).await.step().await.unwrap();
+
+        let deviating_hermes = HermesStub::new(stub_update(&guardians, 101_000, publish_time + 1))
+            .start()
+            .await;
+        let step = keeper(&deployer, &deviating_hermes)
+            .await
+            .step()
+            .await
+            .unwrap();
+
+        assert_eq!(reasons(&step), vec![UpdateReason::Deviation; 2]);
+        assert_eq!(
+            deployer
+                .price_unsafe(feeds()[0].id)
+                .await
+                .unwrap()
+                .value
+                .price,
+            101_000
+        );
+    }
+
+    #[tokio::test]
+    async fn updates_price_feeds_after_heartbeat() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let publish_time = now() as i64 - 60;
+        let hermes = HermesStub::new(stub_update(&guardians, 100_000, publish_time))
+            .start()
+            .await;
+        keeper(&deployer, &hermes).await.step().await.unwrap();
+
+        let later_hermes = HermesStub::new(stub_update(&guardians, 100_000, publish_time + 60))
+            .start()
+            .await;
+        let step = keeper(&deployer, &later_hermes).await.step().await.unwrap();
+
+        assert_eq!(reasons(&step), vec![UpdateReason::Heartbeat; 2]);
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reports_insufficient_balance() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let guardians = LocalGuardianSet::random(1, 4);
+        let single_update_fee = 2_000_000_000;
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            single_update_fee,
+            DEFAULT_VALID_TIME_PERIOD,
+            LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(&guardians),
+        )
+        .await;
+        let hermes = HermesStub::new(stub_update(&guardians, 100_000, now() as i64))
+            .start()
+            .await;
+
+        let step = keeper(&deployer, &hermes).await.step().await.unwrap();
+
+        assert!(matches!(
+            step,
+            KeeperStep::InsufficientBalance { balance, fee }
+                if fee == 2 * single_update_fee && balance < fee
+        ));
+        assert!(
+            !deployer
+                .price_feed_exists(feeds()[0].id)
+                .await
+                .unwrap()
+                .value
+        );
+    }
+
+    #[tokio::test]
+    async fn reports_reverted_update() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let unknown_guardians = LocalGuardianSet::random(1, 4);
+        let hermes = HermesStub::new(stub_update(&unknown_guardians, 100_000, now() as i64))
+            .start()
+            .await;
+
+        let step = keeper(&deployer, &hermes).await.step().await.unwrap();
+
+        assert!(matches!(
+            step,
+            KeeperStep::Reverted { reason, .. } if reason == "SignatureInvalid"
+        ));
+    }
+}
@@ -1,4 +1,15 @@
+pub(crate) mod accumulator;
+pub(crate) mod batch_attestation;
+pub(crate) mod deploy;
+pub(crate) mod fee;
+pub(crate) mod governance;
+pub(crate) mod hermes;
+pub(crate) mod keeper;
+pub(crate) mod network;
+pub(crate) mod pyth_client;
 pub(crate) mod pyth_core;
 pub(crate) mod pyth_info;
 pub(crate) mod pyth_init;
+pub(crate) mod test_utils;
+pub(crate) mod wormhole;
 pub(crate) mod wormhole_guardians;
@@ -0,0 +1,270 @@
+use crate::utils::setup::setup_network_environment;
+use pyth_sdk::{
+    constants::{
+        BETA_5_URL, DEFAULT_SINGLE_UPDATE_FEE, DEFAULT_VALID_TIME_PERIOD, LOCAL_URL, MAINNET_URL,
+    },
+    hermes::DEFAULT_HERMES_URL,
+    network::{DataSourceConfig, DataSourceSet, Network, NetworkConfig, NetworkError},
+    pyth_utils::{
+        default_data_sources, default_governance_data_source, default_price_feed_ids,
+        guardian_set_upgrade_4_vaa,
+    },
+    test_utils::{now, LocalGuardianSet, UpdateDataBuilder, LOCAL_GUARDIAN_SET_INDEX},
+};
+use serde_json::json;
+use std::path::PathBuf;
+
+/// Writes `contents` to a file of the temporary directory unique to `name`.
+fn temp_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("pyth_network_{}_{name}", std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+
+    path
+}
+
+mod success {
+
+    use super::*;
+
+    #[test]
+    fn loads_presets() {
+        let mainnet = NetworkConfig::mainnet();
+        let testnet = NetworkConfig::testnet();
+        let local = NetworkConfig::local();
+
+        assert_eq!(Network::Mainnet.config(), mainnet);
+        assert_eq!(Network::Testnet.config(), testnet);
+        assert_eq!(Network::Local.config(), local);
+        assert_eq!(mainnet.provider_url, MAINNET_URL);
+        assert_eq!(testnet.provider_url, BETA_5_URL);
+        assert_eq!(local.provider_url, LOCAL_URL);
+        for network in [&mainnet, &testnet, &local] {
+            assert_eq!(network.hermes_url, DEFAULT_HERMES_URL);
+            assert_eq!(network.single_update_fee, DEFAULT_SINGLE_UPDATE_FEE);
+            assert_eq!(network.valid_time_period_seconds, DEFAULT_VALID_TIME_PERIOD);
+            assert_eq!(network.price_feed_ids, default_price_feed_ids());
+        }
+        for network in [&mainnet, &testnet] {
+            assert_eq!(
+                network.guardian_set_upgrade_vaa,
+                guardian_set_upgrade_4_vaa()
+            );
+        }
+    }
+
+    #[test]
+    fn local_preset_installs_local_guardian_set() {
+        let local = NetworkConfig::local();
+        let testnet = NetworkConfig::testnet();
+        let guardians = LocalGuardianSet::local();
+
+        let upgrade = local.guardian_set_upgrade().unwrap();
+
+        assert_eq!(upgrade.new_guardian_set_index, LOCAL_GUARDIAN_SET_INDEX);
+        assert_eq!(upgrade.keys, guardians.addresses());
+        assert!(local
+            .data_sources
+            .data_sources()
+            .iter()
+            .all(|data_source| !testnet.data_sources.contains(data_source)));
+        assert_ne!(local.governance_data_source, testnet.governance_data_source);
+    }
+
+    #[test]
+    fn presets_share_stable_data_sources() {
+        let mainnet = NetworkConfig::mainnet().data_sources;
+        let testnet = NetworkConfig::testnet().data_sources;
+
+        assert_eq!(testnet.data_sources(), default_data_sources());
+        assert_eq!(mainnet.0.len(), 3);
+        assert!(mainnet
+            .data_sources()
+            .iter()
+            .all(|data_source| testnet.contains(data_source)));
+    }
+
+    #[test]
+    fn presets_set_governance_data_sources() {
+        let mainnet = NetworkConfig::mainnet().governance_data_source;
+        let testnet = NetworkConfig::testnet().governance_data_source;
+
+        assert_eq!(mainnet.data_source(), default_governance_data_source());
+        assert_ne!(mainnet, testnet);
+        assert_eq!(mainnet.chain_id, testnet.chain_id);
+    }
+
+    #[test]
+    fn parses_json() {
+        let network = NetworkConfig::from_json(
+            &json!({
+                "name": "devnet",
+                "provider_url": "devnet.fuel.network",
+                "single_update_fee": 2,
+                "price_feed_ids": [
+                    "0xff61491a931112ddf1bd8147cd1b641375f79f5825126d665480874634fd0ace",
+                ],
+                "data_sources": [{
+                    "chain_id": 26,
+                    "emitter_address": "0xf8cd23c2ab91237730770bbea08d61005cdda0984348f3f6eecb559638c0bba0",
+                }],
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        assert_eq!(network.name, "devnet");
+        assert_eq!(network.single_update_fee, 2);
+        assert_eq!(network.valid_time_period_seconds, DEFAULT_VALID_TIME_PERIOD);
+        assert_eq!(network.price_feed_ids, default_price_feed_ids()[..1]);
+        assert_eq!(
+            network.data_sources,
+            DataSourceSet(vec![DataSourceConfig::from(&default_data_sources()[1])])
+        );
+        assert_eq!(
+            network.governance_data_source,
+            NetworkConfig::mainnet().governance_data_source
+        );
+    }
+
+    #[test]
+    fn reads_file_by_extension() {
+        let json = temp_file(
+            "devnet.json",
+            &json!({
+                "name": "devnet",
+                "provider_url": "devnet.fuel.network",
+                "data_sources": [],
+            })
+            .to_string(),
+        );
+        let toml = temp_file(
+            "devnet.toml",
+            "name = \"devnet\"\nprovider_url = \"devnet.fuel.network\"\ndata_sources = []",
+        );
+
+        assert_eq!(
+            NetworkConfig::from_file(&json).unwrap(),
+            NetworkConfig::from_file(&toml).unwrap()
+        );
+    }
+
+    #[test]
+    fn decodes_guardian_set_upgrade() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let network = NetworkConfig::local().with_guardian_set_upgrade_vaa(
+            LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(&guardians),
+        );
+
+        let upgrade = network.guardian_set_upgrade().unwrap();
+
+        assert_eq!(upgrade.new_guardian_set_index, 1);
+        assert_eq!(upgrade.keys, guardians.addresses());
+        assert_eq!(
+            NetworkConfig::testnet()
+                .guardian_set_upgrade()
+                .unwrap()
+                .new_guardian_set_index,
+            4
+        );
+    }
+
+    #[tokio::test]
+    async fn constructs_with_network() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let network = NetworkConfig::mainnet().with_guardian_set_upgrade_vaa(
+            LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(&guardians),
+        );
+
+        let pyth = setup_network_environment(&network).await.unwrap();
+
+        assert_eq!(
+            pyth.valid_data_sources().await.unwrap().value,
+            network.data_sources.data_sources()
+        );
+        assert_eq!(
+            pyth.governance_data_source().await.unwrap().value,
+            network.governance_data_source.data_source()
+        );
+        assert_eq!(
+            pyth.single_update_fee().await.unwrap().value,
+            network.single_update_fee
+        );
+        assert_eq!(
+            pyth.current_guardian_set_index().await.unwrap().value,
+            guardians.index()
+        );
+    }
+
+    #[tokio::test]
+    async fn accepts_local_guardian_set_updates_with_local_preset() {
+        let network = NetworkConfig::local();
+        let builder = UpdateDataBuilder::new(LocalGuardianSet::local())
+            .with_emitter(network.data_sources.data_sources()[0].clone())
+            .with_price_feed((
+                default_price_feed_ids()[0],
+                100_000,
+                10,
+                -5,
+                now() as i64,
+                99_000,
+                20,
+            ));
+
+        let pyth = setup_network_environment(&network).await.unwrap();
+        let fee = pyth.update_fee(&[builder.build()]).await.unwrap().value;
+        pyth.update_price_feeds(fee, &[builder.build()])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            pyth.current_guardian_set_index().await.unwrap().value,
+            LOCAL_GUARDIAN_SET_INDEX
+        );
+        assert_eq!(
+            pyth.price_unsafe(builder.messages()[0].id)
+                .await
+                .unwrap()
+                .value,
+            builder.messages()[0].to_price_feed().price
+        );
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[test]
+    fn fails_to_parse_invalid_vaa() {
+        let error = NetworkConfig::from_toml(
+            "name = \"devnet\"\nprovider_url = \"devnet.fuel.network\"\nguardian_set_upgrade_vaa = \"not hex\"\ndata_sources = []",
+        )
+        .unwrap_err();
+
+        assert!(matches!(error, NetworkError::Toml(_)));
+    }
+
+    #[test]
+    fn fails_to_parse_invalid_price_feed_id() {
+        let error = NetworkConfig::from_json(
+            &json!({
+                "name": "devnet",
+                "provider_url": "devnet.fuel.network",
+                "price_feed_ids": ["0x1234"],
+                "data_sources": [],
+            })
+            .to_string(),
+        )
+        .unwrap_err();
+
+        assert!(matches!(error, NetworkError::Json(_)));
+    }
+
+    #[test]
+    fn fails_to_read_missing_file() {
+        assert!(matches!(
+            NetworkConfig::from_file(&PathBuf::from("missing_network.json")),
+            Err(NetworkError::Read { .. })
+        ));
+    }
+}
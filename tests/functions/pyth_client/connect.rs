@@ -0,0 +1,90 @@
+use crate::utils::setup::setup_local_environment;
+use fuels::types::ContractId;
+use pyth_sdk::{
+    constants::DEFAULT_VALID_TIME_PERIOD,
+    pyth_utils::{default_price_feed_ids, Pyth, PythSdkError},
+    test_utils::{now, LocalGuardianSet, UpdateDataBuilder},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn connects_to_existing_deployment() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let builder = UpdateDataBuilder::new(guardians).with_price_feed((
+            default_price_feed_ids()[0],
+            100_000,
+            10,
+            -5,
+            now() as i64,
+            99_000,
+            20,
+        ));
+        let update_data = vec![builder.build()];
+
+        let pyth = Pyth::connect(
+            deployer.instance.contract_id().into(),
+            deployer.wallet.clone(),
+        )
+        .await
+        .unwrap();
+        let fee = pyth.update_fee(&update_data).await.unwrap().value;
+        pyth.update_price_feeds(fee, &update_data).await.unwrap();
+
+        assert_eq!(
+            deployer
+                .price_unsafe(builder.messages()[0].id)
+                .await
+                .unwrap()
+                .value,
+            builder.messages()[0].to_price_feed().price
+        );
+    }
+
+    #[tokio::test]
+    async fn connects_readonly_to_existing_deployment() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+
+        let pyth = Pyth::connect_readonly(
+            deployer.instance.contract_id().into(),
+            deployer.wallet.provider().unwrap().clone(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            pyth.valid_time_period().await.unwrap().value,
+            DEFAULT_VALID_TIME_PERIOD
+        );
+        assert_eq!(
+            pyth.current_guardian_set_index().await.unwrap().value,
+            guardians.index()
+        );
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn fails_to_connect_to_missing_contract() {
+        let deployer = setup_local_environment(&LocalGuardianSet::random(1, 4))
+            .await
+            .unwrap();
+        let contract_id = ContractId::new([1; 32]);
+
+        let Err(error) = Pyth::connect(contract_id, deployer.wallet.clone()).await else {
+            panic!("connected to a missing contract");
+        };
+
+        assert!(matches!(
+            error,
+            PythSdkError::NotPythContract { contract_id: id, .. } if id == contract_id
+        ));
+    }
+}
@@ -0,0 +1,106 @@
+use crate::utils::setup::setup_environment;
+use fuels::{
+    test_helpers::{launch_custom_provider_and_get_wallets, WalletsConfig},
+    types::{transaction::TransactionType, ContractId},
+};
+use pyth_sdk::{
+    constants::DEFAULT_VALID_TIME_PERIOD,
+    pyth_utils::{default_data_sources, Pyth},
+    test_utils::LocalGuardianSet,
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn deploys_at_predicted_contract_id() {
+        let wallet = launch_custom_provider_and_get_wallets(WalletsConfig::default(), None, None)
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+        let salt = [7u8; 32];
+
+        let contract_id = Pyth::deployment_contract_id(wallet.address().into(), salt).unwrap();
+        let pyth = Pyth::deploy_with_salt(wallet, salt).await.unwrap();
+
+        assert_eq!(ContractId::from(pyth.instance.contract_id()), contract_id);
+    }
+
+    #[tokio::test]
+    async fn reports_create_transaction_id() {
+        let wallet = launch_custom_provider_and_get_wallets(WalletsConfig::default(), None, None)
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+        let provider = wallet.provider().unwrap().clone();
+
+        let (_pyth, tx_id) = Pyth::deploy_with_salt_and_tx_id(wallet, [7u8; 32])
+            .await
+            .unwrap();
+
+        let tx = provider.get_transaction_by_id(&tx_id).await.unwrap();
+        assert!(matches!(
+            tx.unwrap().transaction,
+            TransactionType::Create(_)
+        ));
+    }
+
+    #[tokio::test]
+    async fn constructs_with_data_sources() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let guardians = LocalGuardianSet::random(1, 4);
+        let data_sources = default_data_sources()[..2].to_vec();
+        let governance_data_source = default_data_sources()[2].clone();
+
+        deployer
+            .constructor_with_data_sources(
+                data_sources.clone(),
+                governance_data_source.clone(),
+                5,
+                DEFAULT_VALID_TIME_PERIOD * 2,
+                LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(&guardians),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(deployer.single_update_fee().await.unwrap().value, 5);
+        assert_eq!(
+            deployer.valid_time_period().await.unwrap().value,
+            DEFAULT_VALID_TIME_PERIOD * 2
+        );
+        assert_eq!(
+            deployer.valid_data_sources().await.unwrap().value,
+            data_sources
+        );
+        assert_eq!(
+            deployer.governance_data_source().await.unwrap().value,
+            governance_data_source
+        );
+        assert_eq!(
+            deployer.current_guardian_set_index().await.unwrap().value,
+            guardians.index()
+        );
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn fails_to_redeploy_with_same_salt() {
+        let wallet = launch_custom_provider_and_get_wallets(WalletsConfig::default(), None, None)
+            .await
+            .unwrap()
+            .pop()
+            .unwrap();
+        let salt = [7u8; 32];
+
+        Pyth::deploy_with_salt(wallet.clone(), salt).await.unwrap();
+
+        assert!(Pyth::deploy_with_salt(wallet, salt).await.is_err());
+    }
+}
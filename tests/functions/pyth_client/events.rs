@@ -0,0 +1,234 @@
+use crate::utils::setup::{setup_environment, setup_local_environment};
+use fuels::{prelude::Address, types::Identity};
+use pyth_sdk::{
+    constants::{
+        DEFAULT_VALID_TIME_PERIOD, TEST_ACCUMULATOR_ETH_USD_PRICE_FEED,
+        TEST_ACCUMULATOR_USDC_USD_PRICE_FEED, TEST_BATCH_ETH_USD_PRICE_FEED,
+        TEST_BATCH_USDC_USD_PRICE_FEED,
+    },
+    events::PythEvent,
+    pyth_utils::{
+        default_price_feed_ids, guardian_set_upgrade_3_vaa, test_accumulator_update_data_bytes,
+        test_batch_update_data_bytes, ConstructedEvent, OwnershipRenounced, OwnershipSet,
+        PriceFeed, PriceFeedUpdateEvent, UpdatedPriceFeedsEvent,
+    },
+    test_utils::{now, LocalGuardianSet, UpdateDataBuilder},
+};
+
+fn update_data_builder(guardians: &LocalGuardianSet, publish_time: i64) -> UpdateDataBuilder {
+    UpdateDataBuilder::new(guardians.clone()).with_price_feeds(
+        default_price_feed_ids()
+            .into_iter()
+            .map(|id| (id, 100_000, 10, -5, publish_time, 99_000, 20)),
+    )
+}
+
+fn price_feed_update_event(price_feed: &PriceFeed) -> PriceFeedUpdateEvent {
+    PriceFeedUpdateEvent {
+        id: price_feed.id,
+        publish_time: price_feed.price.publish_time,
+        price: price_feed.price.price,
+        confidence: price_feed.price.confidence,
+        exponent: price_feed.price.exponent,
+    }
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn returns_constructed_event() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let guardians = LocalGuardianSet::random(1, 4);
+
+        let response = deployer
+            .constructor(
+                DEFAULT_VALID_TIME_PERIOD,
+                LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(&guardians),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.event,
+            ConstructedEvent {
+                guardian_set_index: guardians.index(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn decodes_constructor_logs_in_order() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let owner = Identity::Address(Address::from(deployer.wallet.address()));
+        let guardians = LocalGuardianSet::random(1, 4);
+
+        let response = deployer
+            .constructor(
+                DEFAULT_VALID_TIME_PERIOD,
+                LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(&guardians),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            deployer.decode_logs(&response.response.receipts).unwrap(),
+            vec![
+                PythEvent::OwnershipSet(OwnershipSet {
+                    new_owner: owner.clone(),
+                }),
+                PythEvent::OwnershipRenounced(OwnershipRenounced {
+                    previous_owner: owner,
+                }),
+                PythEvent::Constructed(response.event),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_new_guardian_set_event() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let new_guardians = LocalGuardianSet::random(2, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+
+        let response = deployer
+            .submit_new_guardian_set(guardians.guardian_set_upgrade_vaa(&new_guardians))
+            .await
+            .unwrap();
+
+        assert_eq!(response.event.new_guardian_set_index, new_guardians.index());
+        assert!(
+            deployer
+                .governance_action_is_consumed(response.event.governance_action_hash)
+                .await
+                .unwrap()
+                .value
+        );
+        assert_eq!(
+            deployer.decode_logs(&response.response.receipts).unwrap(),
+            vec![PythEvent::NewGuardianSet(response.event)]
+        );
+    }
+
+    #[tokio::test]
+    async fn returns_updated_price_feeds_event() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let builder = update_data_builder(&guardians, now() as i64);
+        let update_data = vec![builder.build()];
+        let fee = deployer.update_fee(&update_data).await.unwrap().value;
+
+        let response = deployer
+            .update_price_feeds(fee, &update_data)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.event,
+            UpdatedPriceFeedsEvent {
+                updated_price_feeds: default_price_feed_ids(),
+            }
+        );
+        let mut events: Vec<PythEvent> = builder
+            .messages()
+            .iter()
+            .map(|message| {
+                PythEvent::PriceFeedUpdate(price_feed_update_event(&message.to_price_feed()))
+            })
+            .collect();
+        events.push(PythEvent::UpdatedPriceFeeds(response.event));
+        assert_eq!(
+            deployer.decode_logs(&response.response.receipts).unwrap(),
+            events
+        );
+    }
+
+    #[tokio::test]
+    async fn logs_price_feed_updates_of_fixtures() {
+        for (update_data, eth_usd_price_feed, usdc_usd_price_feed) in [
+            (
+                test_batch_update_data_bytes(),
+                TEST_BATCH_ETH_USD_PRICE_FEED,
+                TEST_BATCH_USDC_USD_PRICE_FEED,
+            ),
+            (
+                test_accumulator_update_data_bytes(),
+                TEST_ACCUMULATOR_ETH_USD_PRICE_FEED,
+                TEST_ACCUMULATOR_USDC_USD_PRICE_FEED,
+            ),
+        ] {
+            let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+            deployer
+                .constructor(DEFAULT_VALID_TIME_PERIOD, guardian_set_upgrade_3_vaa())
+                .await
+                .unwrap();
+            let fee = deployer.update_fee(&update_data).await.unwrap().value;
+
+            let response = deployer
+                .update_price_feeds(fee, &update_data)
+                .await
+                .unwrap();
+
+            let price_feed_updates: Vec<PriceFeedUpdateEvent> = deployer
+                .decode_logs(&response.response.receipts)
+                .unwrap()
+                .into_iter()
+                .filter_map(|event| match event {
+                    PythEvent::PriceFeedUpdate(event) => Some(event),
+                    _ => None,
+                })
+                .collect();
+            assert_eq!(
+                price_feed_updates
+                    .iter()
+                    .map(|event| event.id)
+                    .collect::<Vec<_>>(),
+                response.event.updated_price_feeds
+            );
+            for price_feed in [eth_usd_price_feed, usdc_usd_price_feed] {
+                let event = price_feed_updates
+                    .iter()
+                    .find(|event| event.id == price_feed.id)
+                    .unwrap();
+
+                assert_eq!(*event, price_feed_update_event(&price_feed));
+                assert_eq!(event.price(), price_feed.price);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn omits_price_feeds_not_newer_than_stored() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let publish_time = now() as i64;
+        let newer = vec![update_data_builder(&guardians, publish_time).build()];
+        let older = vec![update_data_builder(&guardians, publish_time - 10).build()];
+        let fee = deployer.update_fee(&newer).await.unwrap().value;
+        deployer.update_price_feeds(fee, &newer).await.unwrap();
+
+        let response = deployer.update_price_feeds(fee, &older).await.unwrap();
+
+        assert!(response.event.updated_price_feeds.is_empty());
+    }
+
+    #[tokio::test]
+    async fn skips_logs_of_other_contracts() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let other = setup_local_environment(&guardians).await.unwrap();
+
+        let response = other
+            .submit_new_guardian_set(
+                guardians.guardian_set_upgrade_vaa(&LocalGuardianSet::random(2, 4)),
+            )
+            .await
+            .unwrap();
+
+        assert!(deployer
+            .decode_logs(&response.response.receipts)
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+use fuels::{
+    accounts::ViewOnlyAccount,
+    prelude::{Address, Transaction, TransactionType, WalletUnlocked, BASE_ASSET_ID},
+    test_helpers::{launch_custom_provider_and_get_wallets, WalletsConfig},
+    types::{ContractId, Identity},
+};
+use pyth_sdk::{
+    constants::DEFAULT_VALID_TIME_PERIOD,
+    events::PythEvent,
+    pyth_utils::{default_price_feed_ids, FeeRefundedEvent, Pyth},
+    test_utils::{now, LocalGuardianSet, UpdateDataBuilder},
+};
+use std::collections::HashSet;
+
+/// Deploys and constructs the contract from one wallet, returning it along with a second wallet to
+/// pay its updates and a builder of an update the contract accepts.
+async fn setup() -> (Pyth, WalletUnlocked, UpdateDataBuilder) {
+    let mut wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(2), Some(1), Some(1_000_000_000)),
+        None,
+        None,
+    )
+    .await
+    .unwrap();
+    let (deployer_wallet, fee_payer) = (wallets.pop().unwrap(), wallets.pop().unwrap());
+
+    let guardians = LocalGuardianSet::random(1, 4);
+    let deployer = Pyth::deploy(deployer_wallet).await.unwrap();
+    deployer
+        .constructor(
+            DEFAULT_VALID_TIME_PERIOD,
+            LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(&guardians),
+        )
+        .await
+        .unwrap();
+
+    let builder = UpdateDataBuilder::new(guardians).with_price_feed((
+        default_price_feed_ids()[0],
+        100_000,
+        10,
+        -5,
+        now() as i64,
+        99_000,
+        20,
+    ));
+
+    (deployer, fee_payer, builder)
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn updates_price_feeds_paid_by_fee_payer() {
+        let (deployer, fee_payer, builder) = setup().await;
+        let provider = fee_payer.provider().unwrap().clone();
+        // a signer owning no coins at all
+        let signer = Pyth::connect(
+            ContractId::from(deployer.instance.contract_id()),
+            WalletUnlocked::new_random(Some(provider.clone())),
+        )
+        .await
+        .unwrap();
+        let update_data = vec![builder.build()];
+        let fee = signer.update_fee(&update_data).await.unwrap().value;
+        let fee_payer_balance = fee_payer.get_asset_balance(&BASE_ASSET_ID).await.unwrap();
+
+        let response = signer
+            .update_price_feeds_with_fee_payer(fee_payer.clone(), fee, &update_data)
+            .await
+            .unwrap();
+
+        let tx = provider
+            .get_transaction_by_id(&response.response.tx_id.unwrap())
+            .await
+            .unwrap()
+            .unwrap()
+            .transaction;
+        let TransactionType::Script(tx) = tx else {
+            panic!("expected a script transaction, got {tx:?}");
+        };
+        let input_owners: HashSet<Address> = tx
+            .inputs()
+            .iter()
+            .filter_map(|input| input.input_owner().copied())
+            .collect();
+        assert_eq!(
+            input_owners,
+            HashSet::from([Address::from(fee_payer.address())])
+        );
+        assert_eq!(tx.witnesses().len(), 2);
+        assert_eq!(
+            signer
+                .wallet
+                .get_asset_balance(&BASE_ASSET_ID)
+                .await
+                .unwrap(),
+            0
+        );
+        assert_eq!(
+            fee_payer.get_asset_balance(&BASE_ASSET_ID).await.unwrap(),
+            fee_payer_balance - fee
+        );
+        assert_eq!(
+            signer
+                .price_unsafe(builder.messages()[0].id)
+                .await
+                .unwrap()
+                .value,
+            builder.messages()[0].to_price_feed().price
+        );
+    }
+
+    #[tokio::test]
+    async fn refunds_excess_payment_to_fee_payer() {
+        let (deployer, fee_payer, builder) = setup().await;
+        let update_data = vec![builder.build()];
+        let fee = deployer.update_fee(&update_data).await.unwrap().value;
+        let fee_payer_balance = fee_payer.get_asset_balance(&BASE_ASSET_ID).await.unwrap();
+
+        let response = deployer
+            .update_price_feeds_with_fee_payer(fee_payer.clone(), fee + 100, &update_data)
+            .await
+            .unwrap();
+
+        assert_eq!(response.refunded_fee().unwrap(), 100);
+        assert_eq!(
+            deployer
+                .decode_logs(&response.response.receipts)
+                .unwrap()
+                .last(),
+            Some(&PythEvent::FeeRefunded(FeeRefundedEvent {
+                amount: 100,
+                recipient: Identity::Address(Address::from(fee_payer.address())),
+            }))
+        );
+        assert_eq!(
+            fee_payer.get_asset_balance(&BASE_ASSET_ID).await.unwrap(),
+            fee_payer_balance - fee
+        );
+        assert_eq!(deployer.fee_balance().await.unwrap().value, fee);
+    }
+}
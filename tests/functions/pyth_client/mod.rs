@@ -0,0 +1,9 @@
+pub(crate) mod connect;
+pub(crate) mod deploy;
+pub(crate) mod events;
+pub(crate) mod fee_payer;
+pub(crate) mod pyth_core;
+pub(crate) mod pyth_governance;
+pub(crate) mod pyth_info;
+pub(crate) mod src5;
+pub(crate) mod wormhole_guardians;
@@ -0,0 +1,219 @@
+use crate::utils::setup::setup_local_environment;
+use fuels::{
+    prelude::Address,
+    types::{errors::Error, Identity},
+};
+use pyth_sdk::{
+    constants::{DEFAULT_VALID_TIME_PERIOD, TAI64_DIFFERENCE},
+    events::{refunded_fee, PythEvent},
+    pyth_utils::{default_price_feed_ids, FeeRefundedEvent},
+    test_utils::{now, LocalGuardianSet, UpdateDataBuilder},
+};
+
+fn update_data_builder(guardians: &LocalGuardianSet) -> UpdateDataBuilder {
+    let publish_time = now() as i64;
+
+    UpdateDataBuilder::new(guardians.clone()).with_price_feeds(
+        default_price_feed_ids()
+            .into_iter()
+            .map(|id| (id, 100_000, 10, -5, publish_time, 99_000, 20)),
+    )
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reads_prices_after_update() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let builder = update_data_builder(&guardians);
+        let update_data = vec![builder.build()];
+
+        let fee = deployer.update_fee(&update_data).await.unwrap().value;
+        deployer
+            .update_price_feeds(fee, &update_data)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            deployer.valid_time_period().await.unwrap().value,
+            DEFAULT_VALID_TIME_PERIOD
+        );
+        for message in builder.messages() {
+            let price_feed = message.to_price_feed();
+
+            assert_eq!(
+                deployer.price_unsafe(message.id).await.unwrap().value,
+                price_feed.price
+            );
+            assert_eq!(
+                deployer.ema_price_unsafe(message.id).await.unwrap().value,
+                price_feed.ema_price
+            );
+            assert_eq!(
+                deployer
+                    .price_no_older_than(u64::MAX, message.id)
+                    .await
+                    .unwrap()
+                    .value,
+                price_feed.price
+            );
+            assert_eq!(
+                deployer
+                    .ema_price_no_older_than(u64::MAX, message.id)
+                    .await
+                    .unwrap()
+                    .value,
+                price_feed.ema_price
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn updates_price_feeds_if_necessary() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let builder = update_data_builder(&guardians);
+        let update_data = vec![builder.build()];
+        let publish_times: Vec<u64> = builder
+            .messages()
+            .iter()
+            .map(|message| message.publish_time as u64 + TAI64_DIFFERENCE)
+            .collect();
+
+        let fee = deployer.update_fee(&update_data).await.unwrap().value;
+        deployer
+            .update_price_feeds_if_necessary(
+                fee,
+                &default_price_feed_ids(),
+                &publish_times,
+                &update_data,
+            )
+            .await
+            .unwrap();
+
+        for message in builder.messages() {
+            assert_eq!(
+                deployer.price_unsafe(message.id).await.unwrap().value,
+                message.to_price_feed().price
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn refunds_excess_payment() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let update_data = vec![update_data_builder(&guardians).build()];
+        let fee = deployer.update_fee(&update_data).await.unwrap().value;
+
+        let response = deployer
+            .update_price_feeds(fee + 100, &update_data)
+            .await
+            .unwrap();
+
+        assert_eq!(response.refunded_fee().unwrap(), 100);
+        assert_eq!(
+            deployer
+                .decode_logs(&response.response.receipts)
+                .unwrap()
+                .last(),
+            Some(&PythEvent::FeeRefunded(FeeRefundedEvent {
+                amount: 100,
+                recipient: Identity::Address(Address::from(deployer.wallet.address())),
+            }))
+        );
+        assert_eq!(deployer.fee_balance().await.unwrap().value, fee);
+    }
+
+    #[tokio::test]
+    async fn refunds_unnecessary_update() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let builder = update_data_builder(&guardians);
+        let update_data = vec![builder.build()];
+        let publish_times: Vec<u64> = builder
+            .messages()
+            .iter()
+            .map(|message| message.publish_time as u64 + TAI64_DIFFERENCE)
+            .collect();
+        let fee = deployer.update_fee(&update_data).await.unwrap().value;
+        deployer
+            .update_price_feeds(fee, &update_data)
+            .await
+            .unwrap();
+
+        let response = deployer
+            .update_price_feeds_if_necessary(
+                fee,
+                &default_price_feed_ids(),
+                &publish_times,
+                &update_data,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(refunded_fee(&response).unwrap(), fee);
+        assert_eq!(
+            deployer.decode_logs(&response.receipts).unwrap(),
+            vec![PythEvent::FeeRefunded(FeeRefundedEvent {
+                amount: fee,
+                recipient: Identity::Address(Address::from(deployer.wallet.address())),
+            })]
+        );
+        assert_eq!(deployer.fee_balance().await.unwrap().value, fee);
+    }
+
+    #[tokio::test]
+    async fn parses_price_feed_update_data() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let builder = update_data_builder(&guardians);
+        let update_data = vec![builder.build()];
+        let publish_time = builder.messages()[0].publish_time as u64 + TAI64_DIFFERENCE;
+
+        let fee = deployer.update_fee(&update_data).await.unwrap().value;
+        let price_feeds = deployer
+            .parse_price_feed_update_data(
+                fee,
+                publish_time,
+                publish_time,
+                &default_price_feed_ids(),
+                &update_data,
+            )
+            .await
+            .unwrap()
+            .value;
+
+        assert_eq!(
+            price_feeds,
+            builder
+                .messages()
+                .iter()
+                .map(|message| message.to_price_feed())
+                .collect::<Vec<_>>()
+        );
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn fails_to_read_unknown_price_feed() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+
+        let error = deployer
+            .price_unsafe(default_price_feed_ids()[0])
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, Error::RevertTransactionError { reason, .. } if reason == "PriceFeedNotFound")
+        );
+    }
+}
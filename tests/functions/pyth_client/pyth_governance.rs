@@ -0,0 +1,368 @@
+use crate::utils::setup::setup_local_environment;
+use fuels::{
+    accounts::ViewOnlyAccount,
+    prelude::{Address, WalletUnlocked, BASE_ASSET_ID},
+    types::{errors::Error, Identity},
+};
+use pyth_sdk::{
+    constants::DEFAULT_SINGLE_UPDATE_FEE,
+    events::PythEvent,
+    governance::{GovernanceAction, GovernanceInstruction},
+    pyth_utils::{
+        default_data_sources, default_governance_data_source, default_price_feed_ids,
+        FeesWithdrawnEvent, Pyth,
+    },
+    test_utils::{now, LocalGuardianSet, UpdateDataBuilder},
+};
+
+fn set_fee(value: u64, expo: u64) -> GovernanceInstruction {
+    GovernanceInstruction::new(GovernanceAction::SetFee { value, expo })
+}
+
+fn withdraw_fees(amount: u64, recipient: Identity) -> GovernanceInstruction {
+    GovernanceInstruction::new(GovernanceAction::WithdrawFees { amount, recipient })
+}
+
+/// Updates the default price feeds with a fee of `value * 10^expo` per update, returning the fee
+/// paid.
+async fn accrue_fees(deployer: &Pyth, guardians: &LocalGuardianSet, value: u64) -> u64 {
+    deployer
+        .execute_governance_instruction(guardians.governance_vaa(
+            &default_governance_data_source(),
+            1,
+            &set_fee(value, 0),
+        ))
+        .await
+        .unwrap();
+    let update_data = vec![UpdateDataBuilder::new(guardians.clone())
+        .with_price_feeds(
+            default_price_feed_ids()
+                .into_iter()
+                .map(|id| (id, 100_000, 10, -5, now() as i64, 99_000, 20)),
+        )
+        .build()];
+    let fee = deployer.update_fee(&update_data).await.unwrap().value;
+    deployer
+        .update_price_feeds(fee, &update_data)
+        .await
+        .unwrap();
+
+    fee
+}
+
+/// A wallet without coins on the provider of `deployer`.
+fn empty_wallet(deployer: &Pyth) -> WalletUnlocked {
+    WalletUnlocked::new_random(deployer.wallet.provider().cloned())
+}
+
+fn identity(wallet: &WalletUnlocked) -> Identity {
+    Identity::Address(Address::from(wallet.address()))
+}
+
+fn revert_reason(error: Error) -> String {
+    match error {
+        Error::RevertTransactionError { reason, .. } => reason,
+        error => panic!("expected a revert, got {error:?}"),
+    }
+}
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reads_governance_state() {
+        let deployer = setup_local_environment(&LocalGuardianSet::random(1, 4))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            deployer.governance_data_source().await.unwrap().value,
+            default_governance_data_source()
+        );
+        assert_eq!(
+            deployer
+                .last_executed_governance_sequence()
+                .await
+                .unwrap()
+                .value,
+            0
+        );
+    }
+
+    #[tokio::test]
+    async fn sets_fee() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let update_data = vec![UpdateDataBuilder::new(guardians.clone())
+            .with_price_feed((
+                default_price_feed_ids()[0],
+                100_000,
+                10,
+                -5,
+                now() as i64,
+                99_000,
+                20,
+            ))
+            .build()];
+        assert_eq!(
+            deployer.compute_update_fee(&update_data).await.unwrap(),
+            DEFAULT_SINGLE_UPDATE_FEE
+        );
+
+        deployer
+            .execute_governance_instruction(guardians.governance_vaa(
+                &default_governance_data_source(),
+                1,
+                &set_fee(5, 2),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(deployer.single_update_fee().await.unwrap().value, 500);
+        assert_eq!(
+            deployer.compute_update_fee(&update_data).await.unwrap(),
+            500
+        );
+        assert_eq!(
+            deployer
+                .last_executed_governance_sequence()
+                .await
+                .unwrap()
+                .value,
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn sets_valid_period() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+
+        deployer
+            .execute_governance_instruction(guardians.governance_vaa(
+                &default_governance_data_source(),
+                1,
+                &GovernanceInstruction::new(GovernanceAction::SetValidPeriod {
+                    valid_time_period_seconds: 120,
+                }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(deployer.valid_time_period().await.unwrap().value, 120);
+    }
+
+    #[tokio::test]
+    async fn sets_data_sources() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let data_sources = default_data_sources();
+        let (removed, kept) = data_sources.split_at(2);
+
+        deployer
+            .execute_governance_instruction(guardians.governance_vaa(
+                &default_governance_data_source(),
+                1,
+                &GovernanceInstruction::new(GovernanceAction::SetDataSources(kept.to_vec())),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            deployer.valid_data_sources().await.unwrap().value,
+            kept.to_vec()
+        );
+        for data_source in removed {
+            assert!(!deployer.valid_data_source(data_source).await.unwrap().value);
+        }
+        for data_source in kept {
+            assert!(deployer.valid_data_source(data_source).await.unwrap().value);
+        }
+    }
+
+    #[tokio::test]
+    async fn executes_instructions_with_increasing_sequences() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+
+        for (sequence, value) in [(1, 2), (5, 3)] {
+            deployer
+                .execute_governance_instruction(guardians.governance_vaa(
+                    &default_governance_data_source(),
+                    sequence,
+                    &set_fee(value, 0),
+                ))
+                .await
+                .unwrap();
+        }
+
+        assert_eq!(deployer.single_update_fee().await.unwrap().value, 3);
+        assert_eq!(
+            deployer
+                .last_executed_governance_sequence()
+                .await
+                .unwrap()
+                .value,
+            5
+        );
+    }
+
+    #[tokio::test]
+    async fn withdraws_fees() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let fee = accrue_fees(&deployer, &guardians, 100).await;
+        let recipient = empty_wallet(&deployer);
+        assert_eq!(deployer.fee_balance().await.unwrap().value, fee);
+
+        let response = deployer
+            .withdraw_fees(guardians.governance_vaa(
+                &default_governance_data_source(),
+                2,
+                &withdraw_fees(fee - 50, identity(&recipient)),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.event,
+            FeesWithdrawnEvent {
+                amount: fee - 50,
+                recipient: identity(&recipient),
+            }
+        );
+        assert_eq!(
+            deployer.decode_logs(&response.response.receipts).unwrap(),
+            vec![PythEvent::FeesWithdrawn(response.event)]
+        );
+        assert_eq!(deployer.fee_balance().await.unwrap().value, 50);
+        assert_eq!(
+            recipient.get_asset_balance(&BASE_ASSET_ID).await.unwrap(),
+            fee - 50
+        );
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn fails_to_replay_instruction() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let vaa = guardians.governance_vaa(&default_governance_data_source(), 1, &set_fee(2, 0));
+        deployer
+            .execute_governance_instruction(vaa.clone())
+            .await
+            .unwrap();
+
+        let error = deployer
+            .execute_governance_instruction(vaa)
+            .await
+            .unwrap_err();
+
+        assert_eq!(revert_reason(error), "OldGovernanceMessage");
+    }
+
+    #[tokio::test]
+    async fn fails_to_execute_old_sequence() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        deployer
+            .execute_governance_instruction(guardians.governance_vaa(
+                &default_governance_data_source(),
+                2,
+                &set_fee(2, 0),
+            ))
+            .await
+            .unwrap();
+
+        let error = deployer
+            .execute_governance_instruction(guardians.governance_vaa(
+                &default_governance_data_source(),
+                1,
+                &set_fee(3, 0),
+            ))
+            .await
+            .unwrap_err();
+
+        assert_eq!(revert_reason(error), "OldGovernanceMessage");
+        assert_eq!(deployer.single_update_fee().await.unwrap().value, 2);
+    }
+
+    #[tokio::test]
+    async fn fails_to_withdraw_more_than_fee_balance() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let fee = accrue_fees(&deployer, &guardians, 100).await;
+        let recipient = empty_wallet(&deployer);
+
+        let error = deployer
+            .withdraw_fees(guardians.governance_vaa(
+                &default_governance_data_source(),
+                2,
+                &withdraw_fees(fee + 1, identity(&recipient)),
+            ))
+            .await
+            .unwrap_err();
+
+        assert_eq!(revert_reason(error), "InsufficientFeeBalance");
+        assert_eq!(deployer.fee_balance().await.unwrap().value, fee);
+    }
+
+    #[tokio::test]
+    async fn fails_to_execute_instruction_of_other_emitter() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+
+        let error = deployer
+            .execute_governance_instruction(guardians.governance_vaa(
+                &default_data_sources()[0],
+                1,
+                &set_fee(2, 0),
+            ))
+            .await
+            .unwrap_err();
+
+        assert_eq!(revert_reason(error), "InvalidGovernanceDataSource");
+    }
+
+    #[tokio::test]
+    async fn fails_to_execute_instruction_signed_by_unknown_guardians() {
+        let deployer = setup_local_environment(&LocalGuardianSet::random(1, 4))
+            .await
+            .unwrap();
+
+        let error = deployer
+            .execute_governance_instruction(LocalGuardianSet::random(1, 4).governance_vaa(
+                &default_governance_data_source(),
+                1,
+                &set_fee(2, 0),
+            ))
+            .await
+            .unwrap_err();
+
+        assert_eq!(revert_reason(error), "SignatureInvalid");
+    }
+
+    #[tokio::test]
+    async fn fails_to_execute_instruction_for_other_chain() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let mut instruction = set_fee(2, 0);
+        instruction.target_chain_id = 2;
+
+        let error = deployer
+            .execute_governance_instruction(guardians.governance_vaa(
+                &default_governance_data_source(),
+                1,
+                &instruction,
+            ))
+            .await
+            .unwrap_err();
+
+        assert_eq!(revert_reason(error), "InvalidGovernanceTarget");
+    }
+}
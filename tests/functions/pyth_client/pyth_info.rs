@@ -0,0 +1,131 @@
+use crate::utils::setup::setup_local_environment;
+use pyth_sdk::{
+    constants::{DEFAULT_SINGLE_UPDATE_FEE, TAI64_DIFFERENCE},
+    pyth_utils::{default_data_sources, default_price_feed_ids, DataSource},
+    test_utils::{now, LocalGuardianSet, UpdateDataBuilder},
+};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reads_configuration() {
+        let deployer = setup_local_environment(&LocalGuardianSet::random(1, 4))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            deployer.single_update_fee().await.unwrap().value,
+            DEFAULT_SINGLE_UPDATE_FEE
+        );
+        assert_eq!(
+            deployer.valid_data_sources().await.unwrap().value,
+            default_data_sources()
+        );
+        assert!(
+            deployer
+                .valid_data_source(&default_data_sources()[0])
+                .await
+                .unwrap()
+                .value
+        );
+        assert!(
+            !deployer
+                .valid_data_source(&DataSource {
+                    chain_id: 0,
+                    emitter_address: default_data_sources()[0].emitter_address,
+                })
+                .await
+                .unwrap()
+                .value
+        );
+    }
+
+    #[tokio::test]
+    async fn reads_price_feed_after_update() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let price_feed_id = default_price_feed_ids()[0];
+        let publish_time = now() as i64;
+        let builder = UpdateDataBuilder::new(guardians).with_price_feed((
+            price_feed_id,
+            100_000,
+            10,
+            -5,
+            publish_time,
+            99_000,
+            20,
+        ));
+        let update_data = vec![builder.build()];
+
+        assert!(
+            !deployer
+                .price_feed_exists(price_feed_id)
+                .await
+                .unwrap()
+                .value
+        );
+
+        let fee = deployer.update_fee(&update_data).await.unwrap().value;
+        deployer
+            .update_price_feeds(fee, &update_data)
+            .await
+            .unwrap();
+
+        assert!(
+            deployer
+                .price_feed_exists(price_feed_id)
+                .await
+                .unwrap()
+                .value
+        );
+        assert_eq!(
+            deployer
+                .price_feed_unsafe(price_feed_id)
+                .await
+                .unwrap()
+                .value,
+            builder.messages()[0].to_price_feed()
+        );
+        assert_eq!(
+            deployer
+                .latest_publish_time(price_feed_id)
+                .await
+                .unwrap()
+                .value,
+            publish_time as u64 + TAI64_DIFFERENCE
+        );
+    }
+
+    #[tokio::test]
+    async fn accrues_update_fees() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        assert_eq!(deployer.fee_balance().await.unwrap().value, 0);
+
+        let mut total_fee = 0;
+        for publish_time in [now() as i64 - 1, now() as i64] {
+            let update_data = vec![UpdateDataBuilder::new(guardians.clone())
+                .with_price_feed((
+                    default_price_feed_ids()[0],
+                    100_000,
+                    10,
+                    -5,
+                    publish_time,
+                    99_000,
+                    20,
+                ))
+                .build()];
+            let fee = deployer.update_fee(&update_data).await.unwrap().value;
+            deployer
+                .update_price_feeds(fee, &update_data)
+                .await
+                .unwrap();
+            total_fee += fee;
+        }
+
+        assert_eq!(total_fee, 2 * DEFAULT_SINGLE_UPDATE_FEE);
+        assert_eq!(deployer.fee_balance().await.unwrap().value, total_fee);
+    }
+}
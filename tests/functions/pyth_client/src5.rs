@@ -0,0 +1,23 @@
+use crate::utils::setup::{setup_environment, setup_local_environment};
+use pyth_sdk::{pyth_utils::State, test_utils::LocalGuardianSet};
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reads_owner() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+
+        assert_eq!(deployer.owner().await.unwrap().value, State::Uninitialized);
+    }
+
+    #[tokio::test]
+    async fn reads_revoked_owner_after_constructor() {
+        let deployer = setup_local_environment(&LocalGuardianSet::random(1, 4))
+            .await
+            .unwrap();
+
+        assert_eq!(deployer.owner().await.unwrap().value, State::Revoked);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::utils::setup::setup_local_environment;
+use fuels::types::errors::Error;
+use pyth_sdk::test_utils::LocalGuardianSet;
+
+mod success {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reads_guardian_set() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+
+        assert_eq!(
+            deployer.current_guardian_set_index().await.unwrap().value,
+            guardians.index()
+        );
+        assert_eq!(
+            deployer
+                .guardian_set(guardians.index())
+                .await
+                .unwrap()
+                .value
+                .keys,
+            guardians.guardian_set().keys
+        );
+    }
+
+    #[tokio::test]
+    async fn submits_new_guardian_set() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let new_guardians = LocalGuardianSet::random(2, 4);
+        let deployer = setup_local_environment(&guardians).await.unwrap();
+        let wormhole_provider = deployer.current_wormhole_provider().await.unwrap().value;
+
+        deployer
+            .submit_new_guardian_set(guardians.guardian_set_upgrade_vaa(&new_guardians))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            deployer.current_guardian_set_index().await.unwrap().value,
+            new_guardians.index()
+        );
+        assert_eq!(
+            deployer.current_wormhole_provider().await.unwrap().value,
+            wormhole_provider
+        );
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn fails_to_submit_guardian_set_signed_by_unknown_guardians() {
+        let deployer = setup_local_environment(&LocalGuardianSet::random(1, 4))
+            .await
+            .unwrap();
+
+        let error = deployer
+            .submit_new_guardian_set(
+                LocalGuardianSet::random(1, 4)
+                    .guardian_set_upgrade_vaa(&LocalGuardianSet::random(2, 4)),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, Error::RevertTransactionError { reason, .. } if reason == "SignatureInvalid")
+        );
+    }
+}
@@ -2,19 +2,35 @@ use crate::utils::interface::{
     pyth_core::{parse_price_feed_updates, update_fee},
     pyth_init::constructor,
 };
-use crate::utils::setup::setup_environment;
+use crate::utils::{
+    hermes::{HermesStub, StubUpdate},
+    setup::setup_environment,
+    update_data::{
+        tampered_message_accumulator_update_data, tampered_proof_accumulator_update_data,
+    },
+};
+use fuels::{
+    prelude::CallParameters,
+    types::{errors::Error, Bytes},
+};
 use pyth_sdk::{
     constants::{
-        DEFAULT_SINGLE_UPDATE_FEE, DEFAULT_VALID_TIME_PERIOD, TEST_ACCUMULATOR_ETH_USD_PRICE_FEED,
-        TEST_ACCUMULATOR_USDC_USD_PRICE_FEED, TEST_BATCH_ETH_USD_PRICE_FEED,
-        TEST_BATCH_USDC_USD_PRICE_FEED,
+        DEFAULT_SINGLE_UPDATE_FEE, DEFAULT_VALID_TIME_PERIOD, TAI64_DIFFERENCE,
+        TEST_ACCUMULATOR_ETH_USD_PRICE_FEED, TEST_ACCUMULATOR_USDC_USD_PRICE_FEED,
+        TEST_BATCH_ETH_USD_PRICE_FEED, TEST_BATCH_USDC_USD_PRICE_FEED,
     },
+    hermes::HermesError,
     pyth_utils::{
         default_data_sources, default_price_feed_ids, guardian_set_upgrade_3_vaa,
-        test_accumulator_update_data_bytes, test_batch_update_data_bytes,
+        test_accumulator_update_data_bytes, test_batch_update_data_bytes, PriceFeed, Pyth,
+        PythSdkError,
     },
 };
 
+fn recorded_hermes() -> HermesStub {
+    HermesStub::accumulator().with_history(vec![StubUpdate::batch(), StubUpdate::accumulator()])
+}
+
 mod success {
 
     use super::*;
@@ -84,4 +100,211 @@ mod success {
         assert_eq!(price_feeds[0], TEST_ACCUMULATOR_ETH_USD_PRICE_FEED);
         assert_eq!(price_feeds[1], TEST_ACCUMULATOR_USDC_USD_PRICE_FEED);
     }
+
+    #[tokio::test]
+    async fn parses_price_feed_updates_fetched_for_a_publish_time() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let hermes = recorded_hermes().start().await;
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            guardian_set_upgrade_3_vaa(),
+        )
+        .await;
+
+        let min_publish_time = TEST_BATCH_ETH_USD_PRICE_FEED.price.publish_time - TAI64_DIFFERENCE;
+        let price_feeds = deployer
+            .parse_price_feed_updates(
+                &hermes.client(),
+                &default_price_feed_ids(),
+                min_publish_time,
+                min_publish_time + DEFAULT_VALID_TIME_PERIOD,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            price_feeds,
+            vec![
+                TEST_BATCH_ETH_USD_PRICE_FEED,
+                TEST_BATCH_USDC_USD_PRICE_FEED
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn parses_first_price_feed_updates_after_a_publish_time() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let hermes = recorded_hermes().start().await;
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            guardian_set_upgrade_3_vaa(),
+        )
+        .await;
+
+        // between the batch and the accumulator fixtures
+        let min_publish_time =
+            TEST_BATCH_ETH_USD_PRICE_FEED.price.publish_time - TAI64_DIFFERENCE + 1;
+        let max_publish_time =
+            TEST_ACCUMULATOR_ETH_USD_PRICE_FEED.price.publish_time - TAI64_DIFFERENCE;
+        let price_feeds = deployer
+            .parse_price_feed_updates(
+                &hermes.client(),
+                &default_price_feed_ids(),
+                min_publish_time,
+                max_publish_time,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            price_feeds,
+            vec![
+                TEST_ACCUMULATOR_ETH_USD_PRICE_FEED,
+                TEST_ACCUMULATOR_USDC_USD_PRICE_FEED
+            ]
+        );
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn fails_when_hermes_has_no_update_after_publish_time() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let hermes = recorded_hermes().start().await;
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            guardian_set_upgrade_3_vaa(),
+        )
+        .await;
+
+        let min_publish_time =
+            TEST_ACCUMULATOR_ETH_USD_PRICE_FEED.price.publish_time - TAI64_DIFFERENCE + 1;
+        let error = deployer
+            .parse_price_feed_updates(
+                &hermes.client(),
+                &default_price_feed_ids(),
+                min_publish_time,
+                min_publish_time + DEFAULT_VALID_TIME_PERIOD,
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            error,
+            PythSdkError::Hermes(HermesError::Status { status: 404, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn fails_when_no_update_is_within_the_publish_time_window() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let hermes = recorded_hermes().start().await;
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            guardian_set_upgrade_3_vaa(),
+        )
+        .await;
+
+        // Hermes answers with the accumulator fixture, which was published after the window
+        let min_publish_time =
+            TEST_BATCH_ETH_USD_PRICE_FEED.price.publish_time - TAI64_DIFFERENCE + 1;
+        let error = deployer
+            .parse_price_feed_updates(
+                &hermes.client(),
+                &default_price_feed_ids(),
+                min_publish_time,
+                min_publish_time + DEFAULT_VALID_TIME_PERIOD,
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(error, PythSdkError::Fuels(_)));
+    }
+
+    #[tokio::test]
+    async fn reverts_for_accumulator_update_with_tampered_message() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            guardian_set_upgrade_3_vaa(),
+        )
+        .await;
+
+        let error = parse_accumulator_update(&deployer, tampered_message_accumulator_update_data())
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, Error::RevertTransactionError { reason, .. } if reason == "InvalidProof")
+        );
+    }
+
+    #[tokio::test]
+    async fn reverts_for_accumulator_update_with_tampered_proof() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            guardian_set_upgrade_3_vaa(),
+        )
+        .await;
+
+        let error = parse_accumulator_update(&deployer, tampered_proof_accumulator_update_data())
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, Error::RevertTransactionError { reason, .. } if reason == "InvalidProof")
+        );
+    }
+
+    async fn parse_accumulator_update(
+        deployer: &Pyth,
+        update_data: Vec<Bytes>,
+    ) -> Result<Vec<PriceFeed>, Error> {
+        let fee = update_fee(&deployer.instance, update_data.clone())
+            .await
+            .value;
+        let max_publish_time = TEST_ACCUMULATOR_ETH_USD_PRICE_FEED.price.publish_time;
+
+        deployer
+            .instance
+            .methods()
+            .parse_price_feed_updates(
+                max_publish_time,
+                max_publish_time - DEFAULT_VALID_TIME_PERIOD,
+                default_price_feed_ids(),
+                update_data,
+            )
+            .call_params(CallParameters::default().with_amount(fee))?
+            .call()
+            .await
+            .map(|response| response.value)
+    }
 }
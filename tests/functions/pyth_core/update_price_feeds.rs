@@ -4,7 +4,13 @@ use crate::utils::interface::{
     pyth_init::constructor,
 };
 
-use crate::utils::setup::setup_environment;
+use crate::utils::{
+    setup::setup_environment,
+    update_data::{
+        tampered_message_accumulator_update_data, tampered_proof_accumulator_update_data,
+    },
+};
+use fuels::types::errors::Error;
 use pyth_sdk::{
     constants::{DEFAULT_SINGLE_UPDATE_FEE, DEFAULT_VALID_TIME_PERIOD},
     pyth_utils::{
@@ -113,3 +119,64 @@ mod success {
         );
     }
 }
+
+mod failure {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reverts_for_accumulator_update_with_tampered_message() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            guardian_set_upgrade_3_vaa(),
+        )
+        .await;
+
+        let update_data = tampered_message_accumulator_update_data();
+        let fee = update_fee(&deployer.instance, update_data.clone())
+            .await
+            .value;
+
+        let error = deployer
+            .update_price_feeds(fee, &update_data)
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, Error::RevertTransactionError { reason, .. } if reason == "InvalidProof")
+        );
+    }
+
+    #[tokio::test]
+    async fn reverts_for_accumulator_update_with_tampered_proof() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            guardian_set_upgrade_3_vaa(),
+        )
+        .await;
+
+        let update_data = tampered_proof_accumulator_update_data();
+        let fee = update_fee(&deployer.instance, update_data.clone())
+            .await
+            .value;
+
+        let error = deployer
+            .update_price_feeds(fee, &update_data)
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, Error::RevertTransactionError { reason, .. } if reason == "InvalidProof")
+        );
+    }
+}
@@ -0,0 +1,203 @@
+use crate::utils::interface::{
+    pyth_core::{price_unsafe, update_fee, update_price_feeds},
+    pyth_init::constructor,
+    wormhole_guardians::{current_guardian_set_index, guardian_set},
+};
+
+use crate::utils::setup::setup_environment;
+use fuels::types::{errors::Error, Bytes};
+use pyth_sdk::{
+    accumulator::{AccumulatorUpdate, PriceFeedMessage},
+    batch_attestation::BatchAttestationUpdate,
+    constants::{DEFAULT_SINGLE_UPDATE_FEE, DEFAULT_VALID_TIME_PERIOD},
+    pyth_utils::{default_data_sources, default_price_feed_ids},
+    test_utils::{now, trading_attestation, LocalGuardianSet},
+    wormhole::VerificationError,
+};
+
+fn price_feed_messages() -> Vec<PriceFeedMessage> {
+    let publish_time = now() as i64;
+
+    default_price_feed_ids()
+        .into_iter()
+        .enumerate()
+        .map(|(i, id)| PriceFeedMessage {
+            id,
+            price: 100_000 * (i as i64 + 1),
+            conf: 50,
+            exponent: -5,
+            publish_time,
+            prev_publish_time: publish_time - 1,
+            ema_price: 99_000 * (i as i64 + 1),
+            ema_conf: 60,
+        })
+        .collect()
+}
+
+fn accumulator_update(guardians: &LocalGuardianSet) -> AccumulatorUpdate {
+    let update_data =
+        guardians.accumulator_update(&default_data_sources()[0], &price_feed_messages());
+
+    AccumulatorUpdate::parse(&update_data.0).unwrap()
+}
+
+mod success {
+
+    use super::*;
+
+    #[test]
+    fn signs_verifiable_accumulator_update() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let messages = price_feed_messages();
+
+        let update_data = guardians.accumulator_update(&default_data_sources()[0], &messages);
+        let update = AccumulatorUpdate::parse(&update_data.0).unwrap();
+
+        update.vaa.verify(&guardians.guardian_set()).unwrap();
+        assert_eq!(update.vaa.guardian_set_index, 1);
+        for (price_update, message) in update.updates.iter().zip(&messages) {
+            assert_eq!(price_update.message, *message);
+            assert_eq!(price_update.merkle_root(), update.merkle_root.digest);
+        }
+    }
+
+    #[test]
+    fn signs_verifiable_batch_update() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let attestations: Vec<_> = price_feed_messages()
+            .iter()
+            .map(trading_attestation)
+            .collect();
+
+        let update_data = guardians.batch_update(&default_data_sources()[0], &attestations);
+        let update = BatchAttestationUpdate::parse(&update_data.0).unwrap();
+
+        update.vaa.verify(&guardians.guardian_set()).unwrap();
+        assert_eq!(update.attestations, attestations);
+    }
+
+    #[tokio::test]
+    async fn installs_local_guardian_set_with_constructor() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let guardians = LocalGuardianSet::random(1, 4);
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(&guardians),
+        )
+        .await;
+
+        assert_eq!(
+            current_guardian_set_index(&deployer.instance).await.value,
+            guardians.index()
+        );
+        assert_eq!(
+            guardian_set(&deployer.instance, guardians.index())
+                .await
+                .value
+                .keys,
+            guardians.guardian_set().keys
+        );
+    }
+
+    #[tokio::test]
+    async fn updates_price_feeds_with_locally_signed_updates() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let guardians = LocalGuardianSet::random(1, 4);
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(&guardians),
+        )
+        .await;
+
+        let messages = price_feed_messages();
+        let update_data = vec![guardians.accumulator_update(&default_data_sources()[0], &messages)];
+        let fee = update_fee(&deployer.instance, update_data.clone())
+            .await
+            .value;
+
+        update_price_feeds(&deployer.instance, fee, update_data).await;
+
+        for message in messages {
+            assert_eq!(
+                price_unsafe(&deployer.instance, message.id).await.value,
+                message.to_price_feed().price
+            );
+        }
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[test]
+    fn fails_verification_without_quorum() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let mut vaa = accumulator_update(&guardians).vaa;
+
+        guardians.sign_with(&mut vaa, &[0, 1]);
+
+        assert_eq!(
+            vaa.verify(&guardians.guardian_set()),
+            Err(VerificationError::NoQuorum {
+                signatures: 2,
+                quorum: 3
+            })
+        );
+    }
+
+    #[test]
+    fn fails_verification_with_unordered_signatures() {
+        let guardians = LocalGuardianSet::random(1, 4);
+        let mut vaa = accumulator_update(&guardians).vaa;
+
+        guardians.sign_with(&mut vaa, &[0, 2, 1]);
+
+        assert_eq!(
+            vaa.verify(&guardians.guardian_set()),
+            Err(VerificationError::SignatureIndicesNotAscending {
+                index: 1,
+                last_index: 2
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn reverts_for_update_without_quorum() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let guardians = LocalGuardianSet::random(1, 4);
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(&guardians),
+        )
+        .await;
+
+        let mut update = accumulator_update(&guardians);
+        guardians.sign_with(&mut update.vaa, &[0, 1]);
+        let update_data = vec![Bytes(update.encode())];
+        let fee = update_fee(&deployer.instance, update_data.clone())
+            .await
+            .value;
+
+        let error = deployer
+            .update_price_feeds(fee, &update_data)
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, Error::RevertTransactionError { reason, .. } if reason == "NoQuorum")
+        );
+    }
+}
@@ -0,0 +1,2 @@
+pub(crate) mod local_guardian_set;
+pub(crate) mod update_data_builder;
@@ -0,0 +1,169 @@
+use crate::utils::interface::{
+    pyth_core::{price_unsafe, update_fee, update_price_feeds},
+    pyth_init::constructor,
+};
+
+use crate::utils::setup::setup_environment;
+use fuels::types::{errors::Error, Bits256};
+use pyth_sdk::{
+    accumulator::AccumulatorUpdate,
+    constants::{DEFAULT_SINGLE_UPDATE_FEE, DEFAULT_VALID_TIME_PERIOD},
+    pyth_utils::{default_data_sources, default_price_feed_ids, DataSource},
+    test_utils::{now, LocalGuardianSet, PriceFeedUpdate, UpdateDataBuilder},
+};
+
+fn price_feed_updates() -> Vec<PriceFeedUpdate> {
+    let publish_time = now() as i64;
+
+    vec![
+        (
+            default_price_feed_ids()[0],
+            300_000_000_000,
+            100_000_000,
+            -8,
+            publish_time,
+            299_000_000_000,
+            200_000_000,
+        ),
+        (
+            default_price_feed_ids()[1],
+            100_000_000,
+            10_000,
+            -8,
+            publish_time,
+            99_990_000,
+            20_000,
+        ),
+    ]
+}
+
+mod success {
+
+    use super::*;
+
+    #[test]
+    fn builds_accumulator_update_of_price_feeds() {
+        let guardians = LocalGuardianSet::random(1, 7);
+        let price_feed_updates = price_feed_updates();
+
+        let update_data = UpdateDataBuilder::new(guardians.clone())
+            .with_price_feeds(price_feed_updates.clone())
+            .build();
+        let update = AccumulatorUpdate::parse(&update_data.0).unwrap();
+
+        update.vaa.verify(&guardians.guardian_set()).unwrap();
+        assert_eq!(
+            update.vaa.emitter_chain_id,
+            default_data_sources()[0].chain_id
+        );
+        assert_eq!(
+            update.vaa.emitter_address,
+            default_data_sources()[0].emitter_address
+        );
+        assert_eq!(update.updates.len(), price_feed_updates.len());
+        for (price_update, price_feed) in update.updates.iter().zip(price_feed_updates) {
+            let (id, price, conf, exponent, publish_time, ema_price, ema_conf) = price_feed;
+            let message = price_update.message;
+
+            assert_eq!(
+                (
+                    message.id,
+                    message.price,
+                    message.conf,
+                    message.exponent,
+                    message.publish_time,
+                    message.ema_price,
+                    message.ema_conf
+                ),
+                (id, price, conf, exponent, publish_time, ema_price, ema_conf)
+            );
+            assert_eq!(price_update.merkle_root(), update.merkle_root.digest);
+        }
+    }
+
+    #[test]
+    fn builds_update_with_configured_emitter() {
+        let emitter = DataSource {
+            chain_id: 26,
+            emitter_address: Bits256([1; 32]),
+        };
+
+        let update_data = UpdateDataBuilder::new(LocalGuardianSet::random(1, 1))
+            .with_emitter(emitter.clone())
+            .with_price_feeds(price_feed_updates())
+            .build();
+        let update = AccumulatorUpdate::parse(&update_data.0).unwrap();
+
+        assert_eq!(update.vaa.emitter_chain_id, emitter.chain_id);
+        assert_eq!(update.vaa.emitter_address, emitter.emitter_address);
+    }
+
+    #[tokio::test]
+    async fn updates_price_feeds_with_built_update() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let builder = UpdateDataBuilder::new(LocalGuardianSet::random(1, 7))
+            .with_price_feeds(price_feed_updates());
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(builder.guardians()),
+        )
+        .await;
+
+        let update_data = vec![builder.build()];
+        let fee = update_fee(&deployer.instance, update_data.clone())
+            .await
+            .value;
+
+        update_price_feeds(&deployer.instance, fee, update_data).await;
+
+        for message in builder.messages() {
+            assert_eq!(
+                price_unsafe(&deployer.instance, message.id).await.value,
+                message.to_price_feed().price
+            );
+        }
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[tokio::test]
+    async fn reverts_for_update_from_unknown_emitter() {
+        let (_oracle_contract_id, deployer) = setup_environment().await.unwrap();
+        let builder = UpdateDataBuilder::new(LocalGuardianSet::random(1, 7))
+            .with_emitter(DataSource {
+                chain_id: 26,
+                emitter_address: Bits256([1; 32]),
+            })
+            .with_price_feeds(price_feed_updates());
+
+        constructor(
+            &deployer.instance,
+            default_data_sources(),
+            DEFAULT_SINGLE_UPDATE_FEE,
+            DEFAULT_VALID_TIME_PERIOD,
+            LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(builder.guardians()),
+        )
+        .await;
+
+        let update_data = vec![builder.build()];
+        let fee = update_fee(&deployer.instance, update_data.clone())
+            .await
+            .value;
+
+        let error = deployer
+            .update_price_feeds(fee, &update_data)
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(error, Error::RevertTransactionError { reason, .. } if reason == "InvalidUpdateDataSource")
+        );
+    }
+}
@@ -0,0 +1,161 @@
+use pyth_sdk::{
+    accumulator::AccumulatorUpdate,
+    pyth_utils::{
+        guardian_set_upgrade_3_vaa, guardian_set_upgrade_4_vaa, test_accumulator_update_data_bytes,
+        test_batch_update_data_bytes, GuardianSet,
+    },
+    wormhole::{guardian_key, quorum, GuardianSetUpgrade, Vaa, VerificationError},
+};
+
+fn guardian_set_3() -> GuardianSet {
+    let vaa = Vaa::parse(&guardian_set_upgrade_3_vaa().0).unwrap();
+
+    GuardianSetUpgrade::parse(&vaa.payload)
+        .unwrap()
+        .guardian_set()
+}
+
+fn test_accumulator_vaa() -> Vaa {
+    AccumulatorUpdate::parse(&test_accumulator_update_data_bytes()[0].0)
+        .unwrap()
+        .vaa
+}
+
+mod success {
+
+    use super::*;
+
+    #[test]
+    fn parses_guardian_set_upgrade() {
+        let vaa = Vaa::parse(&guardian_set_upgrade_3_vaa().0).unwrap();
+        let upgrade = GuardianSetUpgrade::parse(&vaa.payload).unwrap();
+
+        assert_eq!(vaa.guardian_set_index, 2);
+        assert_eq!(upgrade.new_guardian_set_index, 3);
+        assert_eq!(upgrade.keys.len(), 19);
+        assert_eq!(upgrade.guardian_set().expiration_time, 0);
+    }
+
+    #[test]
+    fn recovers_signers() {
+        let vaa = test_accumulator_vaa();
+        let guardian_set = guardian_set_3();
+
+        let signers = vaa.signers().unwrap();
+
+        assert_eq!(signers.len(), vaa.signatures.len());
+        for (signer, signature) in signers.iter().zip(&vaa.signatures) {
+            assert_eq!(
+                guardian_key(*signer),
+                guardian_set.keys[usize::from(signature.index)]
+            );
+        }
+    }
+
+    #[test]
+    fn verifies_price_update_vaas() {
+        let guardian_set = guardian_set_3();
+
+        test_accumulator_vaa().verify(&guardian_set).unwrap();
+        for update in test_batch_update_data_bytes() {
+            Vaa::parse(&update.0)
+                .unwrap()
+                .verify(&guardian_set)
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn verifies_guardian_set_upgrade() {
+        let vaa = Vaa::parse(&guardian_set_upgrade_4_vaa().0).unwrap();
+
+        vaa.verify(&guardian_set_3()).unwrap();
+        assert_eq!(
+            GuardianSetUpgrade::parse(&vaa.payload)
+                .unwrap()
+                .new_guardian_set_index,
+            4
+        );
+    }
+
+    #[test]
+    fn computes_quorum_like_the_contract() {
+        assert_eq!(quorum(1), 1);
+        assert_eq!(quorum(3), 3);
+        assert_eq!(quorum(19), 13);
+    }
+}
+
+mod failure {
+
+    use super::*;
+
+    #[test]
+    fn fails_with_tampered_payload() {
+        let mut vaa = test_accumulator_vaa();
+        vaa.payload[0] ^= 1;
+
+        let error = vaa.verify(&guardian_set_3()).unwrap_err();
+
+        assert_eq!(
+            error,
+            VerificationError::SignatureInvalid(vaa.signatures[0].index)
+        );
+    }
+
+    #[test]
+    fn fails_without_quorum() {
+        let mut vaa = test_accumulator_vaa();
+        vaa.signatures.pop();
+
+        let error = vaa.verify(&guardian_set_3()).unwrap_err();
+
+        assert_eq!(
+            error,
+            VerificationError::NoQuorum {
+                signatures: 12,
+                quorum: 13
+            }
+        );
+    }
+
+    #[test]
+    fn fails_with_signature_indices_not_ascending() {
+        let mut vaa = test_accumulator_vaa();
+        vaa.signatures.swap(0, 1);
+
+        let error = vaa.verify(&guardian_set_3()).unwrap_err();
+
+        assert_eq!(
+            error,
+            VerificationError::SignatureIndicesNotAscending {
+                index: vaa.signatures[1].index,
+                last_index: vaa.signatures[0].index
+            }
+        );
+    }
+
+    #[test]
+    fn fails_when_guardian_set_has_no_key_for_a_signature() {
+        let vaa = test_accumulator_vaa();
+        let mut guardian_set = guardian_set_3();
+        guardian_set.keys.truncate(1);
+
+        let error = vaa.verify(&guardian_set).unwrap_err();
+
+        assert!(matches!(
+            error,
+            VerificationError::GuardianSetKeyIrretrievable(_)
+        ));
+    }
+
+    #[test]
+    fn fails_with_incompatible_version() {
+        let mut vaa = test_accumulator_vaa();
+        vaa.version = 2;
+
+        let error = vaa.verify(&guardian_set_3()).unwrap_err();
+
+        assert_eq!(error, VerificationError::VMVersionIncompatible(2));
+    }
+}
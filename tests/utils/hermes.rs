@@ -0,0 +1,317 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{header::CONTENT_TYPE, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use base64::prelude::{Engine, BASE64_STANDARD};
+use fuels::types::Bytes;
+use pyth_sdk::{
+    constants::{
+        TAI64_DIFFERENCE, TEST_ACCUMULATOR_ETH_USD_PRICE_FEED,
+        TEST_ACCUMULATOR_USDC_USD_PRICE_FEED, TEST_BATCH_ETH_USD_PRICE_FEED,
+        TEST_BATCH_USDC_USD_PRICE_FEED,
+    },
+    hermes::{
+        BinaryPriceUpdate, EncodingType, HermesClient, HermesConfig, ParsedPriceUpdate,
+        PriceUpdate, RpcPrice,
+    },
+    pyth_utils::{
+        test_accumulator_update_data_bytes, test_batch_update_data_bytes, Price, PriceFeed,
+    },
+};
+use std::{
+    net::TcpListener,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+/// Local stand-in for the Hermes price service, serving fixed update data.
+pub(crate) struct HermesStub {
+    state: StubState,
+}
+
+/// Update data together with the price feeds it contains.
+#[derive(Clone)]
+pub(crate) struct StubUpdate {
+    update_data: Vec<Bytes>,
+    price_feeds: Vec<PriceFeed>,
+}
+
+impl StubUpdate {
+    pub(crate) fn new(update_data: Vec<Bytes>, price_feeds: Vec<PriceFeed>) -> Self {
+        Self {
+            update_data,
+            price_feeds,
+        }
+    }
+
+    /// `TEST_ACCUMULATOR_UPDATE_DATA` and its price feeds.
+    pub(crate) fn accumulator() -> Self {
+        Self::new(
+            test_accumulator_update_data_bytes(),
+            vec![
+                TEST_ACCUMULATOR_ETH_USD_PRICE_FEED,
+                TEST_ACCUMULATOR_USDC_USD_PRICE_FEED,
+            ],
+        )
+    }
+
+    /// `TEST_BATCH_UPDATE_DATA` and its price feeds.
+    pub(crate) fn batch() -> Self {
+        Self::new(
+            test_batch_update_data_bytes(),
+            vec![
+                TEST_BATCH_ETH_USD_PRICE_FEED,
+                TEST_BATCH_USDC_USD_PRICE_FEED,
+            ],
+        )
+    }
+}
+
+/// The query parameters of every request served, in order.
+type Requests = Arc<Mutex<Vec<Vec<(String, String)>>>>;
+
+#[derive(Clone)]
+struct StubState {
+    latest: StubUpdate,
+    // recorded updates served by publish time
+    history: Arc<Vec<StubUpdate>>,
+    // updates sent on the n-th connection to the stream, after which the connection is closed
+    stream: Arc<Vec<Vec<StubUpdate>>>,
+    stream_connections: Arc<AtomicUsize>,
+    delay: Duration,
+    status: StatusCode,
+    requests: Requests,
+}
+
+pub(crate) struct RunningHermesStub {
+    url: String,
+    stream_connections: Arc<AtomicUsize>,
+    requests: Requests,
+}
+
+impl HermesStub {
+    pub(crate) fn new(latest: StubUpdate) -> Self {
+        Self {
+            state: StubState {
+                latest,
+                history: Arc::new(vec![]),
+                stream: Arc::new(vec![]),
+                stream_connections: Arc::new(AtomicUsize::new(0)),
+                delay: Duration::ZERO,
+                status: StatusCode::OK,
+                requests: Arc::new(Mutex::new(vec![])),
+            },
+        }
+    }
+
+    /// Serves `TEST_ACCUMULATOR_UPDATE_DATA` and its price feeds.
+    pub(crate) fn accumulator() -> Self {
+        Self::new(StubUpdate::accumulator())
+    }
+
+    /// Serves `history` from `/v2/updates/price/{publish_time}`, answering with the first update
+    /// whose prices were all published at or after the requested time.
+    pub(crate) fn with_history(mut self, history: Vec<StubUpdate>) -> Self {
+        self.state.history = Arc::new(history);
+        self
+    }
+
+    pub(crate) fn with_stream(mut self, stream: Vec<Vec<StubUpdate>>) -> Self {
+        self.state.stream = Arc::new(stream);
+        self
+    }
+
+    pub(crate) fn with_delay(mut self, delay: Duration) -> Self {
+        self.state.delay = delay;
+        self
+    }
+
+    pub(crate) fn with_status(mut self, status: StatusCode) -> Self {
+        self.state.status = status;
+        self
+    }
+
+    pub(crate) async fn start(self) -> RunningHermesStub {
+        let requests = self.state.requests.clone();
+        let stream_connections = self.state.stream_connections.clone();
+        let router = Router::new()
+            .route("/v2/updates/price/latest", get(latest_price_updates))
+            .route("/v2/updates/price/stream", get(price_update_stream))
+            .route("/v2/updates/price/:publish_time", get(price_updates_at))
+            .with_state(self.state);
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        listener.set_nonblocking(true).unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(
+            axum::Server::from_tcp(listener)
+                .unwrap()
+                .serve(router.into_make_service()),
+        );
+
+        RunningHermesStub {
+            url,
+            stream_connections,
+            requests,
+        }
+    }
+}
+
+impl RunningHermesStub {
+    pub(crate) fn url(&self) -> &str {
+        &self.url
+    }
+
+    pub(crate) fn client(&self) -> HermesClient {
+        HermesClient::new(HermesConfig::default().with_base_url(self.url())).unwrap()
+    }
+
+    pub(crate) fn stream_connections(&self) -> usize {
+        self.stream_connections.load(Ordering::SeqCst)
+    }
+
+    /// The `ids[]` query parameters of every request served so far.
+    pub(crate) fn requested_ids(&self) -> Vec<Vec<String>> {
+        self.requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|query| {
+                query
+                    .iter()
+                    .filter(|(key, _)| key == "ids[]")
+                    .map(|(_, value)| value.clone())
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+async fn latest_price_updates(
+    State(state): State<StubState>,
+    Query(query): Query<Vec<(String, String)>>,
+) -> Response {
+    state.requests.lock().unwrap().push(query.clone());
+    tokio::time::sleep(state.delay).await;
+
+    if state.status != StatusCode::OK {
+        return (state.status, "stubbed failure").into_response();
+    }
+
+    Json(price_update(&state.latest, &query)).into_response()
+}
+
+async fn price_updates_at(
+    State(state): State<StubState>,
+    Path(publish_time): Path<u64>,
+    Query(query): Query<Vec<(String, String)>>,
+) -> Response {
+    state.requests.lock().unwrap().push(query.clone());
+
+    if state.status != StatusCode::OK {
+        return (state.status, "stubbed failure").into_response();
+    }
+
+    let publish_time = publish_time + TAI64_DIFFERENCE;
+    match state.history.iter().find(|update| {
+        update
+            .price_feeds
+            .iter()
+            .all(|price_feed| price_feed.price.publish_time >= publish_time)
+    }) {
+        Some(update) => Json(price_update(update, &query)).into_response(),
+        None => (StatusCode::NOT_FOUND, "no update at the given time").into_response(),
+    }
+}
+
+async fn price_update_stream(
+    State(state): State<StubState>,
+    Query(query): Query<Vec<(String, String)>>,
+) -> Response {
+    state.requests.lock().unwrap().push(query.clone());
+    let connection = state.stream_connections.fetch_add(1, Ordering::SeqCst);
+
+    if state.status != StatusCode::OK {
+        return (state.status, "stubbed failure").into_response();
+    }
+
+    let events: String = state
+        .stream
+        .get(connection)
+        .into_iter()
+        .flatten()
+        .map(|update| {
+            format!(
+                ": keep-alive\n\ndata: {}\n\n",
+                serde_json::to_string(&price_update(update, &query)).unwrap()
+            )
+        })
+        .collect();
+
+    ([(CONTENT_TYPE, "text/event-stream")], events).into_response()
+}
+
+fn price_update(update: &StubUpdate, query: &[(String, String)]) -> PriceUpdate {
+    let param = |name: &str| {
+        query
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    };
+    let encoding = match param("encoding") {
+        Some("base64") => EncodingType::Base64,
+        _ => EncodingType::Hex,
+    };
+    let ids: Vec<&str> = query
+        .iter()
+        .filter(|(key, _)| key == "ids[]")
+        .map(|(_, value)| value.as_str())
+        .collect();
+
+    let data = update
+        .update_data
+        .iter()
+        .map(|update| match encoding {
+            EncodingType::Hex => hex::encode(&update.0),
+            EncodingType::Base64 => BASE64_STANDARD.encode(&update.0),
+        })
+        .collect();
+    let parsed = (param("parsed") == Some("true")).then(|| {
+        update
+            .price_feeds
+            .iter()
+            .filter(|price_feed| ids.contains(&hex::encode(price_feed.id.0).as_str()))
+            .map(parsed_price_update)
+            .collect()
+    });
+
+    PriceUpdate {
+        binary: BinaryPriceUpdate { encoding, data },
+        parsed,
+    }
+}
+
+fn parsed_price_update(price_feed: &PriceFeed) -> ParsedPriceUpdate {
+    ParsedPriceUpdate {
+        id: hex::encode(price_feed.id.0),
+        price: rpc_price(&price_feed.price),
+        ema_price: rpc_price(&price_feed.ema_price),
+        metadata: None,
+    }
+}
+
+fn rpc_price(price: &Price) -> RpcPrice {
+    RpcPrice {
+        price: price.price as i64,
+        conf: price.confidence,
+        expo: -(price.exponent as i32),
+        publish_time: (price.publish_time - TAI64_DIFFERENCE) as i64,
+    }
+}
@@ -1,6 +1,6 @@
 use fuels::{
     accounts::wallet::WalletUnlocked,
-    prelude::{Bytes, CallParameters, TxPolicies},
+    prelude::{Bytes, CallParameters, TxDependencyExtension, TxPolicies},
     programs::call_response::FuelCallResponse,
     types::Bits256,
 };
@@ -127,6 +127,7 @@ pub(crate) async fn update_price_feeds(
         .update_price_feeds(update_data)
         .call_params(CallParameters::default().with_amount(fee))
         .unwrap()
+        .append_variable_outputs(1)
         .call()
         .await
         .unwrap()
@@ -144,6 +145,7 @@ pub(crate) async fn update_price_feeds_if_necessary(
         .update_price_feeds_if_necessary(price_feed_ids, publish_times, update_data)
         .call_params(CallParameters::default().with_amount(fee))
         .unwrap()
+        .append_variable_outputs(1)
         .call()
         .await
         .unwrap()
@@ -2,7 +2,7 @@ use fuels::{
     accounts::wallet::WalletUnlocked, prelude::Bytes, programs::call_response::FuelCallResponse,
 };
 
-use pyth_sdk::pyth_utils::{DataSource, PythOracleContract};
+use pyth_sdk::pyth_utils::{default_governance_data_source, DataSource, PythOracleContract};
 
 pub(crate) async fn constructor(
     contract: &PythOracleContract<WalletUnlocked>,
@@ -15,6 +15,7 @@ pub(crate) async fn constructor(
         .methods()
         .constructor(
             data_sources,
+            default_governance_data_source(),
             single_update_fee,
             valid_time_period_seconds,
             wormhole_guardian_set_upgrade,
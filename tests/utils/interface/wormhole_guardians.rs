@@ -37,7 +37,7 @@ pub(crate) async fn governance_action_is_consumed(
         .unwrap()
 }
 
-pub(crate) async fn _guardian_set(
+pub(crate) async fn guardian_set(
     contract: &PythOracleContract<WalletUnlocked>,
     index: u32,
 ) -> FuelCallResponse<GuardianSet> {
@@ -0,0 +1,53 @@
+use crate::utils::hermes::{RunningHermesStub, StubUpdate};
+use fuels::prelude::WalletUnlocked;
+use pyth_sdk::{
+    keeper::{FeedConfig, Keeper},
+    pyth_utils::{default_price_feed_ids, Pyth},
+    test_utils::{LocalGuardianSet, UpdateDataBuilder},
+};
+
+pub(crate) fn feeds() -> Vec<FeedConfig> {
+    default_price_feed_ids()
+        .into_iter()
+        .take(2)
+        .map(|id| FeedConfig {
+            id,
+            heartbeat_seconds: 60,
+            deviation_bps: 50,
+        })
+        .collect()
+}
+
+/// Update data of `feeds()` at `price` and `publish_time`, signed by `guardians`.
+pub(crate) fn stub_update(
+    guardians: &LocalGuardianSet,
+    price: i64,
+    publish_time: i64,
+) -> StubUpdate {
+    let builder = UpdateDataBuilder::new(guardians.clone()).with_price_feeds(
+        feeds()
+            .iter()
+            .map(|feed| (feed.id, price, 10, -5, publish_time, price, 10)),
+    );
+
+    StubUpdate::new(
+        vec![builder.build()],
+        builder
+            .messages()
+            .iter()
+            .map(|message| message.to_price_feed())
+            .collect(),
+    )
+}
+
+/// A keeper of `feeds()` for the contract of `deployer`, following `hermes`.
+pub(crate) async fn keeper(deployer: &Pyth, hermes: &RunningHermesStub) -> Keeper<WalletUnlocked> {
+    let pyth = Pyth::connect(
+        deployer.instance.contract_id().into(),
+        deployer.wallet.clone(),
+    )
+    .await
+    .unwrap();
+
+    Keeper::new(pyth, hermes.client(), feeds())
+}
@@ -1,2 +1,5 @@
+pub(crate) mod hermes;
 pub(crate) mod interface;
+pub(crate) mod keeper;
 pub(crate) mod setup;
+pub(crate) mod update_data;
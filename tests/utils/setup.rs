@@ -2,7 +2,11 @@ use fuels::{
     test_helpers::{launch_custom_provider_and_get_wallets, WalletsConfig},
     types::{errors::Error, ContractId},
 };
-use pyth_sdk::pyth_utils::Pyth;
+use pyth_sdk::{
+    network::{DataSourceConfig, NetworkConfig},
+    pyth_utils::{default_data_sources, default_governance_data_source, Pyth},
+    test_utils::LocalGuardianSet,
+};
 
 pub(crate) async fn setup_environment() -> Result<(ContractId, Pyth), Error> {
     // Launch a local network and deploy the contract
@@ -25,3 +29,27 @@ pub(crate) async fn setup_environment() -> Result<(ContractId, Pyth), Error> {
 
     Ok((pyth.instance.contract_id().into(), pyth))
 }
+
+/// Deploys the contract and constructs it with the parameters of `network`.
+pub(crate) async fn setup_network_environment(network: &NetworkConfig) -> Result<Pyth, Error> {
+    let (_oracle_contract_id, deployer) = setup_environment().await?;
+
+    deployer.constructor_with_network(network).await?;
+
+    Ok(deployer)
+}
+
+/// Deploys the contract and constructs it with the local network preset, the default data sources
+/// and governance data source in place of its emitters and `guardians` as its current guardian set.
+pub(crate) async fn setup_local_environment(guardians: &LocalGuardianSet) -> Result<Pyth, Error> {
+    let mut network = NetworkConfig::local().with_guardian_set_upgrade_vaa(
+        LocalGuardianSet::random(0, 1).guardian_set_upgrade_vaa(guardians),
+    );
+    network.data_sources = default_data_sources()
+        .iter()
+        .map(DataSourceConfig::from)
+        .collect();
+    network.governance_data_source = DataSourceConfig::from(&default_governance_data_source());
+
+    setup_network_environment(&network).await
+}
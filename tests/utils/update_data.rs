@@ -0,0 +1,29 @@
+use fuels::types::Bytes;
+use pyth_sdk::{accumulator::AccumulatorUpdate, pyth_utils::test_accumulator_update_data_bytes};
+
+/// `TEST_ACCUMULATOR_UPDATE_DATA` with a bit of the first message's price flipped.
+pub(crate) fn tampered_message_accumulator_update_data() -> Vec<Bytes> {
+    // skip the message type and price feed id
+    tamper_first_message(|message_offset, _| message_offset + 33)
+}
+
+/// `TEST_ACCUMULATOR_UPDATE_DATA` with a bit of the first message's first sibling hash flipped.
+pub(crate) fn tampered_proof_accumulator_update_data() -> Vec<Bytes> {
+    // skip the message and the proof size
+    tamper_first_message(|message_offset, message_size| message_offset + message_size + 1)
+}
+
+fn tamper_first_message(offset: impl Fn(usize, usize) -> usize) -> Vec<Bytes> {
+    let mut data = test_accumulator_update_data_bytes().remove(0).0;
+    let message = AccumulatorUpdate::parse(&data).unwrap().updates[0]
+        .encoded_message
+        .clone();
+    let message_offset = data
+        .windows(message.len())
+        .position(|window| window == message)
+        .unwrap();
+
+    data[offset(message_offset, message.len())] ^= 1;
+
+    vec![Bytes(data)]
+}